@@ -5,7 +5,7 @@ use bitcountry_runtime::{
     AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, ContractsConfig, CouncilConfig,
     DemocracyConfig, GrandpaConfig, ImOnlineConfig, SessionConfig, SessionKeys, StakerStatus,
     StakingConfig, ElectionsConfig, IndicesConfig, SocietyConfig, SudoConfig, SystemConfig,
-    ContinuumConfig, TechnicalCommitteeConfig, wasm_binary_unwrap, BABE_GENESIS_EPOCH_CONFIG,
+    ContinuumConfig, NftModuleConfig, TechnicalCommitteeConfig, wasm_binary_unwrap, BABE_GENESIS_EPOCH_CONFIG,
 };
 use bitcountry_runtime::Block;
 use bitcountry_runtime::constants::currency::*;
@@ -338,6 +338,7 @@ fn testnet_genesis(
             initial_max_bound: (-100, 100),
             spot_price: 5 * DOLLARS,
         }),
+        nft: Some(NftModuleConfig::default()),
         // tokenization: Some(TokenConfig {
         // 	init_token_id: 0
         // })
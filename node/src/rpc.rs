@@ -8,7 +8,7 @@
 #![warn(missing_docs)]
 
 use std::sync::Arc;
-use primitives::{Block, BlockNumber, AccountId, Index, Balance, Hash};
+use primitives::{Block, BlockNumber, AccountId, Index, Balance, Hash, ClassId, TokenId};
 use sp_api::ProvideRuntimeApi;
 use sp_transaction_pool::TransactionPool;
 use sp_blockchain::{HeaderBackend, HeaderMetadata, Error as BlockChainError};
@@ -83,6 +83,7 @@ pub fn create_full<C, P, SC, B>(deps: FullDeps<C, P, SC, B>) -> jsonrpc_core::Io
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_nft_rpc::NftRuntimeApi<Block, AccountId, ClassId, TokenId, Balance>,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
@@ -94,6 +95,7 @@ pub fn create_full<C, P, SC, B>(deps: FullDeps<C, P, SC, B>) -> jsonrpc_core::Io
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
     use sc_finality_grandpa_rpc::{GrandpaApi, GrandpaRpcHandler};
     use sc_consensus_babe_rpc::BabeRpcHandler;
+    use pallet_nft_rpc::{Nft, NftApi};
 
     let mut io = jsonrpc_core::IoHandler::default();
     let FullDeps {
@@ -123,6 +125,9 @@ pub fn create_full<C, P, SC, B>(deps: FullDeps<C, P, SC, B>) -> jsonrpc_core::Io
     io.extend_with(
         TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone()))
     );
+    io.extend_with(
+        NftApi::to_delegate(Nft::new(client.clone()))
+    );
     io.extend_with(
         sc_consensus_babe_rpc::BabeApi::to_delegate(
             BabeRpcHandler::new(
@@ -5,13 +5,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::string_lit_as_bytes)]
 
-use auction_manager::{Auction, OnNewBidResult, AuctionHandler, Change, AuctionInfo, AuctionItem, AuctionType};
-use frame_support::{traits::{Currency, ExistenceRequirement, ReservableCurrency, LockableCurrency}};
+use auction_manager::{Auction, OnNewBidResult, AuctionHandler, Change, AuctionInfo, AuctionItem, AuctionType, DutchAuctionConfig, PriceDecay, SealedBidSettlement};
+use codec::{Decode, Encode};
+use frame_support::{transactional, traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency, LockableCurrency}};
 use frame_system::{self as system, ensure_signed};
 use pallet_continuum::Pallet as ContinuumModule;
 use pallet_nft::Module as NFTModule;
 use primitives::{ItemId, AuctionId, AssetId, continuum::Continuum};
-use sp_runtime::{traits::{One, Zero}, DispatchError, DispatchResult};
+use sp_runtime::{traits::{AccountIdConversion, Hash, One, Zero, Saturating, SaturatedConversion}, DispatchError, DispatchResult, ModuleId, Perbill, RuntimeDebug};
 pub use pallet::*;
 
 #[cfg(test)]
@@ -21,6 +22,39 @@ mod mock;
 
 pub struct AuctionLogicHandler;
 
+/// Minimum amount a new bid has to add on top of the current highest bid
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum BidIncrement<Balance> {
+    Absolute(Balance),
+    Percentage(Perbill),
+}
+
+/// Price an auction has to reach for the item to be sold
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ReservePrice<Balance> {
+    pub amount: Balance,
+    /// Visible reserves reject lower bids straight away, hidden reserves are only checked when the auction ends
+    pub visible: bool,
+}
+
+/// Commitment of a bidder in a sealed bid auction
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SealedBid<Hash, Balance> {
+    /// `hash(auction_id, bidder, amount, salt)` of the hidden bid
+    pub commitment: Hash,
+    /// Reserved deposit, the revealed amount can not exceed it
+    pub deposit: Balance,
+    /// Bid amount once revealed
+    pub revealed: Option<Balance>,
+}
+
+/// Seller defined rules applied to bids of an English auction
+#[derive(Encode, Decode, Copy, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct AuctionBidRules<Balance> {
+    pub reserve_price: Option<ReservePrice<Balance>>,
+    pub min_increment: Option<BidIncrement<Balance>>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
@@ -45,6 +79,24 @@ pub mod pallet {
         type Currency: ReservableCurrency<Self::AccountId>
         + LockableCurrency<Self::AccountId, Moment=Self::BlockNumber>;
         type ContinuumHandler: Continuum<Self::AccountId>;
+        /// Bids placed within this many blocks of the end push the end back to this many blocks after the bid
+        #[pallet::constant]
+        type AntiSnipeDuration: Get<Self::BlockNumber>;
+        /// Maximum number of blocks an auction can be extended by in total
+        #[pallet::constant]
+        type MaxAuctionExtension: Get<Self::BlockNumber>;
+        /// Number of blocks at the end of a sealed bid auction reserved for revealing bids
+        #[pallet::constant]
+        type SealedBidRevealPeriod: Get<Self::BlockNumber>;
+        /// Minimum deposit to reserve when committing a sealed bid
+        #[pallet::constant]
+        type MinimumSealedBidDeposit: Get<BalanceOf<Self>>;
+        /// Maximum number of sealed bids per auction
+        #[pallet::constant]
+        type MaxSealedBids: Get<u32>;
+        /// Treasury receiving the deposits of sealed bids that are never revealed
+        #[pallet::constant]
+        type TreasuryModuleId: Get<ModuleId>;
     }
 
     #[pallet::storage]
@@ -72,6 +124,26 @@ pub mod pallet {
     /// Index auctions by end time.
     pub(super) type AuctionEndTime<T: Config> = StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, AuctionId, (), OptionQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn auction_bid_rules)]
+    /// Reserve price and minimum increment of auctions that set them
+    pub(super) type BidRules<T: Config> = StorageMap<_, Twox64Concat, AuctionId, AuctionBidRules<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn sealed_bids)]
+    /// Committed bids of sealed bid auctions
+    pub(super) type SealedBids<T: Config> = StorageDoubleMap<_, Twox64Concat, AuctionId, Twox64Concat, T::AccountId, SealedBid<T::Hash, BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn sealed_bid_count)]
+    /// Number of sealed bids committed to each auction
+    pub(super) type SealedBidCount<T: Config> = StorageMap<_, Twox64Concat, AuctionId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn auction_extension)]
+    /// Total number of blocks each auction has been extended by late bids
+    pub(super) type AuctionExtensions<T: Config> = StorageMap<_, Twox64Concat, AuctionId, T::BlockNumber, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     #[pallet::metadata()]
@@ -81,6 +153,22 @@ pub mod pallet {
         NewAuctionItem(AuctionId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
         AuctionFinalized(AuctionId, T::AccountId, BalanceOf<T>),
         BuyNowFinalised(AuctionId, T::AccountId, BalanceOf<T>),
+        /// Royalty paid to the class creator on settlement. [auction_id, beneficiary, royalty_amount]
+        RoyaltyPaid(AuctionId, T::AccountId, BalanceOf<T>),
+        /// Auction end pushed back by a late bid. [auction_id, new_end]
+        AuctionExtended(AuctionId, T::BlockNumber),
+        /// Auction ended below its reserve price, the item stays with the seller. [auction_id, high_bidder, high_bid_price]
+        ReserveNotMet(AuctionId, T::AccountId, BalanceOf<T>),
+        /// Seller withdrew an auction without bids. [auction_id]
+        AuctionCancelled(AuctionId),
+        /// Seller changed the price of a buy now listing. [auction_id, new_price]
+        BuyNowPriceUpdated(AuctionId, BalanceOf<T>),
+        /// Sealed bid committed. [auction_id, bidder, deposit]
+        SealedBidCommitted(AuctionId, T::AccountId, BalanceOf<T>),
+        /// Sealed bid revealed. [auction_id, bidder, bidding_amount]
+        SealedBidRevealed(AuctionId, T::AccountId, BalanceOf<T>),
+        /// Deposit of an unrevealed sealed bid sent to the treasury. [auction_id, bidder, deposit]
+        SealedBidSlashed(AuctionId, T::AccountId, BalanceOf<T>),
     }
 
     #[pallet::call]
@@ -105,11 +193,22 @@ pub mod pallet {
 
                 ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
 
+                let bid_rules = Self::auction_bid_rules(id).unwrap_or_default();
                 if let Some(ref current_bid) = auction.bid {
                     ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
+                    if let Some(min_increment) = bid_rules.min_increment {
+                        let increment = match min_increment {
+                            BidIncrement::Absolute(amount) => amount,
+                            BidIncrement::Percentage(percent) => percent * current_bid.1,
+                        };
+                        ensure!(value >= current_bid.1.saturating_add(increment), Error::<T>::BidIncrementTooLow);
+                    }
                 } else {
                     ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
                 }
+                if let Some(reserve_price) = bid_rules.reserve_price {
+                    ensure!(!reserve_price.visible || value >= reserve_price.amount, Error::<T>::BidBelowReservePrice);
+                }
                 let bid_result = T::Handler::on_new_bid(
                     block_number,
                     id,
@@ -126,6 +225,10 @@ pub mod pallet {
                 auction.bid = Some((from.clone(), value));
                 Self::deposit_event(Event::Bid(id, from, value));
 
+                if let Change::NewValue(Some(new_end)) = bid_result.auction_end_change {
+                    Self::extend_auction(id, auction, new_end);
+                }
+
                 Ok(())
             })?;
 
@@ -138,7 +241,7 @@ pub mod pallet {
 
             let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
             let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-            ensure!(auction_item.auction_type == AuctionType::BuyNow, Error::<T>::InvalidAuctionType);
+            ensure!(matches!(auction_item.auction_type, AuctionType::BuyNow | AuctionType::Dutch(_)), Error::<T>::InvalidAuctionType);
 
             ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
 
@@ -149,12 +252,23 @@ pub mod pallet {
                 ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
             }
 
-            ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+            //Dutch auctions sell at the current price as long as the buyer accepts at least that much
+            let value = match auction_item.auction_type {
+                AuctionType::Dutch(_) => {
+                    let price = Self::dutch_auction_price(auction_id, block_number).ok_or(Error::<T>::AuctionNotExist)?;
+                    ensure!(value >= price, Error::<T>::InvalidBuyItNowPrice);
+                    price
+                }
+                _ => {
+                    ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+                    value
+                }
+            };
             ensure!(<T as Config>::Currency::free_balance(&from) >= value, Error::<T>::InsufficientFunds);
 
             Self::remove_auction(auction_id.clone(), auction_item.item_id);
             //Transfer balance from buy it now user to asset owner
-            let currency_transfer = <T as Config>::Currency::transfer(&from, &auction_item.recipient, value, ExistenceRequirement::KeepAlive);
+            let currency_transfer = Self::settle_payment(auction_id, auction_item.item_id, &from, &auction_item.recipient, value);
             match currency_transfer {
                 Err(_e) => {}
                 Ok(_v) => {
@@ -186,14 +300,23 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-        pub(super) fn create_new_auction(origin: OriginFor<T>, item_id: ItemId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+        pub(super) fn create_new_auction(
+            origin: OriginFor<T>,
+            item_id: ItemId,
+            value: BalanceOf<T>,
+            reserve_price: Option<ReservePrice<BalanceOf<T>>>,
+            min_increment: Option<BidIncrement<BalanceOf<T>>>,
+        ) -> DispatchResultWithPostInfo {
             let from = ensure_signed(origin)?;
 
             let start_time: T::BlockNumber = <system::Module<T>>::block_number();
             let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); //add 7 days block for default auction
 
             let auction_id = Self::create_auction(AuctionType::Auction, item_id, Some(end_time), from.clone(), value.clone(), start_time)?;
+            if reserve_price.is_some() || min_increment.is_some() {
+                <BidRules<T>>::insert(auction_id, AuctionBidRules { reserve_price, min_increment });
+            }
             Self::deposit_event(Event::NewAuctionItem(auction_id, from, value, value));
 
             Ok(().into())
@@ -211,25 +334,190 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(4))]
+        pub(super) fn cancel_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+            let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+            ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateAuction);
+            ensure!(auction.bid.is_none(), Error::<T>::AuctionHasBids);
+            ensure!(Self::sealed_bid_count(auction_id) == 0, Error::<T>::AuctionHasBids);
+
+            Self::remove_auction(auction_id, auction_item.item_id);
+            <AuctionItems<T>>::remove(auction_id);
+
+            Self::deposit_event(Event::AuctionCancelled(auction_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub(super) fn update_buy_now_price(origin: OriginFor<T>, auction_id: AuctionId, new_price: BalanceOf<T>) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            ensure!(!new_price.is_zero(), Error::<T>::InvalidBuyItNowPrice);
+            //Items of sold or expired listings stay behind, only live listings can be repriced
+            ensure!(<Auctions<T>>::contains_key(auction_id), Error::<T>::AuctionNotExist);
+
+            <AuctionItems<T>>::try_mutate_exists(auction_id, |auction_item| -> DispatchResult {
+                let auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+                ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateAuction);
+                ensure!(auction_item.auction_type == AuctionType::BuyNow, Error::<T>::InvalidAuctionType);
+
+                auction_item.amount = new_price;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BuyNowPriceUpdated(auction_id, new_price));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub(super) fn create_new_sealed_bid_auction(
+            origin: OriginFor<T>,
+            item_id: ItemId,
+            minimum_bid: BalanceOf<T>,
+            settlement: SealedBidSettlement,
+        ) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            let start_time: T::BlockNumber = <system::Module<T>>::block_number();
+            let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get();
+
+            Self::create_auction(AuctionType::SealedBid(settlement), item_id, Some(end_time), from, minimum_bid, start_time)?;
+
+            Ok(().into())
+        }
+
+        /// Commit to a hidden bid, `commitment` is `hash(auction_id, bidder, amount, salt)` of the bid revealed later
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(5, 3))]
+        pub(super) fn commit_sealed_bid(
+            origin: OriginFor<T>,
+            auction_id: AuctionId,
+            commitment: T::Hash,
+            deposit: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+            let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+            ensure!(matches!(auction_item.auction_type, AuctionType::SealedBid(_)), Error::<T>::InvalidAuctionType);
+            ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
+
+            let block_number = <frame_system::Module<T>>::block_number();
+            ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+            ensure!(block_number < Self::reveal_phase_start(&auction_item), Error::<T>::SealedBidCommitPhaseOver);
+
+            ensure!(deposit >= T::MinimumSealedBidDeposit::get(), Error::<T>::SealedBidDepositTooLow);
+            ensure!(!<SealedBids<T>>::contains_key(auction_id, &from), Error::<T>::SealedBidAlreadyCommitted);
+            let bid_count = Self::sealed_bid_count(auction_id);
+            ensure!(bid_count < T::MaxSealedBids::get(), Error::<T>::TooManySealedBids);
+
+            <T as Config>::Currency::reserve(&from, deposit)?;
+            <SealedBidCount<T>>::insert(auction_id, bid_count + 1);
+            <SealedBids<T>>::insert(auction_id, &from, SealedBid {
+                commitment,
+                deposit,
+                revealed: None,
+            });
+
+            Self::deposit_event(Event::SealedBidCommitted(auction_id, from, deposit));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub(super) fn reveal_sealed_bid(
+            origin: OriginFor<T>,
+            auction_id: AuctionId,
+            amount: BalanceOf<T>,
+            salt: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+            let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+
+            let block_number = <frame_system::Module<T>>::block_number();
+            ensure!(block_number >= Self::reveal_phase_start(&auction_item), Error::<T>::SealedBidNotInRevealPhase);
+            ensure!(auction.end.map_or(false, |end| block_number < end), Error::<T>::AuctionIsExpired);
+
+            <SealedBids<T>>::try_mutate(auction_id, &from, |sealed_bid| -> DispatchResult {
+                let sealed_bid = sealed_bid.as_mut().ok_or(Error::<T>::SealedBidNotFound)?;
+                ensure!(sealed_bid.revealed.is_none(), Error::<T>::SealedBidAlreadyRevealed);
+                ensure!(sealed_bid.commitment == Self::sealed_bid_commitment(auction_id, &from, amount, salt), Error::<T>::InvalidSealedBidReveal);
+                ensure!(amount <= sealed_bid.deposit, Error::<T>::SealedBidExceedsDeposit);
+
+                sealed_bid.revealed = Some(amount);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::SealedBidRevealed(auction_id, from, amount));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub(super) fn create_new_dutch_auction(
+            origin: OriginFor<T>,
+            item_id: ItemId,
+            start_price: BalanceOf<T>,
+            floor_price: BalanceOf<T>,
+            decay: PriceDecay<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let from = ensure_signed(origin)?;
+
+            let start_time: T::BlockNumber = <system::Module<T>>::block_number();
+            let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get();
+            let auction_type = AuctionType::Dutch(DutchAuctionConfig { floor_price, decay });
+
+            Self::create_auction(auction_type, item_id, Some(end_time), from, start_price, start_time)?;
+
+            Ok(().into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-        /// dummy `on_initialize` to return the weight used in `on_finalize`.
-        // fn on_initialize(now: T::BlockNumber) -> Weight {
-        // 	T::WeightInfo::on_finalize(<AuctionEndTime<T>>::iter_prefix(&now).count() as u32)
-        // }
+        /// `on_initialize` to return the weight used in `on_finalize`.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight = db_weight.reads(1);
+            for (auction_id, _) in <AuctionEndTime<T>>::iter_prefix(&now) {
+                //Settlement, payment and item transfer of the auction
+                weight = weight.saturating_add(db_weight.reads_writes(8, 10));
+                //Refund or slash of every sealed bid
+                let sealed_bids = Self::sealed_bid_count(auction_id) as Weight;
+                weight = weight.saturating_add(db_weight.reads_writes(1 + 2 * sealed_bids, 1 + 2 * sealed_bids));
+            }
+            weight
+        }
 
         fn on_finalize(now: T::BlockNumber) {
             for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
                 if let Some(auction) = <Auctions<T>>::get(&auction_id) {
                     if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
+                        let reserve_price = Self::auction_bid_rules(auction_id).and_then(|rules| rules.reserve_price);
                         Self::remove_auction(auction_id.clone(), auction_item.item_id);
+                        if let AuctionType::SealedBid(settlement) = auction_item.auction_type {
+                            Self::finalize_sealed_bid_auction(auction_id, &auction_item, settlement);
+                            continue;
+                        }
                         //Transfer balance from high bidder to asset owner
                         if let Some(current_bid) = auction.bid {
                             let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
                             <T as Config>::Currency::unreserve(&high_bidder, high_bid_price);
-                            let currency_transfer = <T as Config>::Currency::transfer(&high_bidder, &auction_item.recipient, high_bid_price, ExistenceRequirement::KeepAlive);
+                            //Item stays with the seller when the reserve is not met
+                            if let Some(reserve_price) = reserve_price {
+                                if high_bid_price < reserve_price.amount {
+                                    Self::deposit_event(Event::ReserveNotMet(auction_id, high_bidder, high_bid_price));
+                                    continue;
+                                }
+                            }
+                            let currency_transfer = Self::settle_payment(auction_id, auction_item.item_id, &high_bidder, &auction_item.recipient, high_bid_price);
                             match currency_transfer {
                                 Err(_e) => continue,
                                 Ok(_v) => {
@@ -285,7 +573,32 @@ pub mod pallet {
         InvalidBuyItNowPrice,
         InsufficientFunds,
         InvalidAuctionType,
-        AssetAlreadyInAuction
+        AssetAlreadyInAuction,
+        //Dutch auction floor is above the start price or the step schedule is empty
+        InvalidDutchAuctionConfig,
+        //Bid does not add the minimum increment on top of the current bid
+        BidIncrementTooLow,
+        //Bid is below the visible reserve price
+        BidBelowReservePrice,
+        //Only the seller can cancel or edit an auction
+        NoPermissionToUpdateAuction,
+        //Auctions with bids can not be cancelled
+        AuctionHasBids,
+        //Auction duration does not leave room for both sealed bid phases
+        InvalidSealedBidSchedule,
+        //Sealed bids can only be committed before the reveal phase
+        SealedBidCommitPhaseOver,
+        //Sealed bids can only be revealed during the reveal phase
+        SealedBidNotInRevealPhase,
+        SealedBidDepositTooLow,
+        SealedBidAlreadyCommitted,
+        TooManySealedBids,
+        SealedBidNotFound,
+        SealedBidAlreadyRevealed,
+        //Revealed amount and salt do not match the commitment
+        InvalidSealedBidReveal,
+        //Revealed amount is larger than the reserved deposit
+        SealedBidExceedsDeposit,
     }
 
     impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
@@ -341,13 +654,23 @@ pub mod pallet {
         }
 
         fn create_auction(
-            auction_type: AuctionType,
+            auction_type: AuctionType<Self::Balance>,
             item_id: ItemId,
             _end: Option<T::BlockNumber>,
             recipient: T::AccountId,
             initial_amount: Self::Balance,
             _start: T::BlockNumber,
         ) -> Result<AuctionId, DispatchError> {
+            if let AuctionType::Dutch(config) = auction_type {
+                ensure!(config.floor_price <= initial_amount, Error::<T>::InvalidDutchAuctionConfig);
+                if let PriceDecay::Stepwise { interval, amount } = config.decay {
+                    ensure!(interval > 0 && !amount.is_zero(), Error::<T>::InvalidDutchAuctionConfig);
+                }
+            }
+            if let AuctionType::SealedBid(_) = auction_type {
+                ensure!(T::AuctionTimeToClose::get() > T::SealedBidRevealPeriod::get(), Error::<T>::InvalidSealedBidSchedule);
+            }
+
             match item_id {
                 ItemId::NFT(asset_id) => {
                     //FIXME - Remove in prod - For debugging purpose
@@ -360,6 +683,7 @@ pub mod pallet {
                     let class_info_data = class_info.data;
                     ensure!(class_info_data.token_type.is_transferable(), Error::<T>::NoPermissionToCreateAuction);
                     ensure!(Self::assets_in_auction(asset_id) == None, Error::<T>::AssetAlreadyInAuction);
+                    NFTModule::<T>::ensure_asset_unlocked(&asset_id)?;
 
                     let start_time = <system::Module<T>>::block_number();
                     let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); //add 7 days block for default auction
@@ -423,6 +747,8 @@ pub mod pallet {
                 if let Some(end_block) = auction.end {
                     <AuctionEndTime<T>>::remove(end_block, id);
                     <Auctions<T>>::remove(&id);
+                    <AuctionExtensions<T>>::remove(&id);
+                    <BidRules<T>>::remove(&id);
                     match item_id {
                         ItemId::NFT(asset_id) => {
                              <AssetsInAuction<T>>::remove(asset_id);
@@ -481,14 +807,23 @@ pub mod pallet {
     for Module<T>
     {
         fn on_new_bid(
-            _now: T::BlockNumber,
-            _id: AuctionId,
+            now: T::BlockNumber,
+            id: AuctionId,
             _new_bid: (T::AccountId, BalanceOf<T>),
             _last_bid: Option<(T::AccountId, BalanceOf<T>)>,
         ) -> OnNewBidResult<T::BlockNumber> {
+            //Extend the auction when the bid lands inside the anti-snipe window
+            let anti_snipe_duration = T::AntiSnipeDuration::get();
+            let auction_end_change = match Self::auctions(id).and_then(|auction| auction.end) {
+                Some(end) if !anti_snipe_duration.is_zero() && end.saturating_sub(now) < anti_snipe_duration => {
+                    Change::NewValue(Some(now + anti_snipe_duration))
+                }
+                _ => Change::NoChange,
+            };
+
             OnNewBidResult {
                 accept_bid: true,
-                auction_end_change: Change::NoChange,
+                auction_end_change,
             }
         }
 
@@ -496,5 +831,162 @@ pub mod pallet {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Commitment `bidder` submits for a sealed bid of `amount`, bound to the auction and the bidder so it can not be copied
+    pub fn sealed_bid_commitment(auction_id: AuctionId, bidder: &T::AccountId, amount: BalanceOf<T>, salt: T::Hash) -> T::Hash {
+        T::Hashing::hash_of(&(auction_id, bidder, amount, salt))
+    }
+
+    /// First block of the reveal phase of a sealed bid auction
+    fn reveal_phase_start(auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>) -> T::BlockNumber {
+        auction_item.end_time.saturating_sub(T::SealedBidRevealPeriod::get())
+    }
+
+    /// Refund revealed bids, slash unrevealed deposits and sell the item to the highest revealed bid
+    fn finalize_sealed_bid_auction(
+        auction_id: AuctionId,
+        auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        settlement: SealedBidSettlement,
+    ) {
+        let treasury: T::AccountId = T::TreasuryModuleId::get().into_account();
+        let mut highest_bid: Option<(T::AccountId, BalanceOf<T>)> = None;
+        //Second price never drops below the minimum bid
+        let mut second_price = auction_item.initial_amount;
+
+        <SealedBidCount<T>>::remove(auction_id);
+        for (bidder, sealed_bid) in <SealedBids<T>>::drain_prefix(auction_id) {
+            match sealed_bid.revealed {
+                Some(amount) => {
+                    <T as Config>::Currency::unreserve(&bidder, sealed_bid.deposit);
+                    if amount < auction_item.initial_amount {
+                        continue;
+                    }
+                    let highest_amount = highest_bid.as_ref().map(|(_, highest_amount)| *highest_amount);
+                    match highest_amount {
+                        Some(highest_amount) if amount <= highest_amount => {
+                            second_price = second_price.max(amount);
+                        }
+                        _ => {
+                            if let Some(highest_amount) = highest_amount {
+                                second_price = second_price.max(highest_amount);
+                            }
+                            highest_bid = Some((bidder, amount));
+                        }
+                    }
+                }
+                None => {
+                    let _ = <T as Config>::Currency::repatriate_reserved(&bidder, &treasury, sealed_bid.deposit, BalanceStatus::Free);
+                    Self::deposit_event(Event::SealedBidSlashed(auction_id, bidder, sealed_bid.deposit));
+                }
+            }
+        }
+
+        let (winner, highest_amount) = match highest_bid {
+            Some(bid) => bid,
+            None => return,
+        };
+        let price = match settlement {
+            SealedBidSettlement::FirstPrice => highest_amount,
+            SealedBidSettlement::SecondPrice => second_price,
+        };
+
+        if Self::settle_payment(auction_id, auction_item.item_id, &winner, &auction_item.recipient, price).is_err() {
+            return;
+        }
+        let item_transfer = match auction_item.item_id {
+            ItemId::NFT(asset_id) => NFTModule::<T>::do_transfer(&auction_item.recipient, &winner, asset_id).map(|_| ()),
+            ItemId::Spot(spot_id, country_id) => T::ContinuumHandler::transfer_spot(spot_id, &auction_item.recipient, &(winner.clone(), country_id)).map(|_| ()),
+            _ => return,
+        };
+        if item_transfer.is_ok() {
+            Self::deposit_event(Event::AuctionFinalized(auction_id, winner, price));
+        }
+    }
+
+    /// Current price of a dutch auction at block `now`, `None` if the auction is not a dutch auction
+    pub fn dutch_auction_price(auction_id: AuctionId, now: T::BlockNumber) -> Option<BalanceOf<T>> {
+        let auction_item = Self::get_auction_item(auction_id)?;
+        let config = match auction_item.auction_type {
+            AuctionType::Dutch(config) => config,
+            _ => return None,
+        };
+
+        let start_price = auction_item.initial_amount;
+        let duration = auction_item.end_time.saturating_sub(auction_item.start_time);
+        let elapsed = now.saturating_sub(auction_item.start_time).min(duration);
+
+        let price = match config.decay {
+            PriceDecay::Linear => {
+                if duration.is_zero() {
+                    return Some(config.floor_price);
+                }
+                let decayed = Perbill::from_rational_approximation(
+                    elapsed.saturated_into::<u32>(),
+                    duration.saturated_into::<u32>(),
+                ) * start_price.saturating_sub(config.floor_price);
+                start_price.saturating_sub(decayed)
+            }
+            PriceDecay::Stepwise { interval, amount } => {
+                let steps = elapsed.saturated_into::<u32>() / interval.max(1);
+                start_price.saturating_sub(amount.saturating_mul(steps.into()))
+            }
+        };
+
+        Some(price.max(config.floor_price))
+    }
+
+    /// Move the auction end to `new_end`, capped so the total extension stays within `MaxAuctionExtension`
+    fn extend_auction(
+        id: AuctionId,
+        auction: &mut AuctionInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        new_end: T::BlockNumber,
+    ) {
+        let old_end = match auction.end {
+            Some(end) => end,
+            None => return,
+        };
+        let extended = Self::auction_extension(id);
+        let remaining = T::MaxAuctionExtension::get().saturating_sub(extended);
+        let new_end = new_end.min(old_end.saturating_add(remaining));
+        if new_end <= old_end {
+            return;
+        }
+
+        <AuctionEndTime<T>>::remove(&old_end, id);
+        <AuctionEndTime<T>>::insert(&new_end, id, ());
+        auction.end = Some(new_end);
+        <AuctionItems<T>>::mutate(id, |auction_item| {
+            if let Some(auction_item) = auction_item {
+                auction_item.end_time = new_end;
+            }
+        });
+        <AuctionExtensions<T>>::insert(id, extended + (new_end - old_end));
 
+        Self::deposit_event(Event::AuctionExtended(id, new_end));
+    }
 
+    /// Pay the seller, taking the class royalty out of the sale price for NFT items
+    #[transactional]
+    fn settle_payment(
+        auction_id: AuctionId,
+        item_id: ItemId,
+        buyer: &T::AccountId,
+        seller: &T::AccountId,
+        price: BalanceOf<T>,
+    ) -> DispatchResult {
+        let mut seller_amount = price;
+
+        if let ItemId::NFT(asset_id) = item_id {
+            if let Some((beneficiary, royalty_fee)) = NFTModule::<T>::get_royalty_info(&asset_id) {
+                let royalty_amount = royalty_fee * price;
+                if !royalty_amount.is_zero() && &beneficiary != seller {
+                    <T as Config>::Currency::transfer(buyer, &beneficiary, royalty_amount, ExistenceRequirement::KeepAlive)?;
+                    seller_amount = price - royalty_amount;
+                    Self::deposit_event(Event::RoyaltyPaid(auction_id, beneficiary, royalty_amount));
+                }
+            }
+        }
+
+        <T as Config>::Currency::transfer(buyer, seller, seller_amount, ExistenceRequirement::KeepAlive)
+    }
+}
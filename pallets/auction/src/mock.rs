@@ -3,8 +3,10 @@
 use super::*;
 use frame_support::{construct_runtime, parameter_types, pallet_prelude::Hooks};
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup, ModuleId};
-use primitives::{AuctionId, continuum::Continuum};
+use sp_runtime::{testing::Header, traits::{IdentifyAccount, IdentityLookup, Lazy, Verify}, ModuleId, Perbill, RuntimeDebug};
+use codec::{Decode, Encode};
+use primitives::{AuctionId, Amount, CurrencyId, CurrencyIdAllocator, continuum::Continuum};
+use orml_traits::parameter_type_with_key;
 use pallet_nft::{AssetHandler};
 
 use crate as auction;
@@ -19,6 +21,7 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const CLASS_ID: u32 = 0;
 pub const COLLECTION_ID: u64 = 0;
 
@@ -77,10 +80,7 @@ impl AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> for Handler {
     fn on_new_bid(now: BlockNumber, id: AuctionId, new_bid: (AccountId, Balance), last_bid: Option<(AccountId, Balance)>) -> OnNewBidResult<BlockNumber> {
         //Test with Alice bid
         if new_bid.0 == ALICE {
-            OnNewBidResult {
-                accept_bid: true,
-                auction_end_change: Change::NoChange,
-            }
+            NftAuctionModule::on_new_bid(now, id, new_bid, last_bid)
         } else {
             OnNewBidResult {
                 accept_bid: false,
@@ -104,6 +104,12 @@ impl AssetHandler for NftAssetHandler {
 
 parameter_types! {
     pub const AuctionTimeToClose: u64 = 100; //Test auction end within 100 blocks
+    pub const AntiSnipeDuration: u64 = 10;
+    pub const MaxAuctionExtension: u64 = 15;
+    pub const SealedBidRevealPeriod: u64 = 20;
+    pub const MinimumSealedBidDeposit: Balance = 10;
+    pub const MaxSealedBids: u32 = 3;
+    pub const TreasuryModuleId: ModuleId = ModuleId(*b"bit/trsy");
 }
 
 impl Config for Runtime {
@@ -112,29 +118,87 @@ impl Config for Runtime {
     type Handler = Handler;
     type Currency = Balances;
     type ContinuumHandler = Continuumm;
+    type AntiSnipeDuration = AntiSnipeDuration;
+    type MaxAuctionExtension = MaxAuctionExtension;
+    type SealedBidRevealPeriod = SealedBidRevealPeriod;
+    type MinimumSealedBidDeposit = MinimumSealedBidDeposit;
+    type MaxSealedBids = MaxSealedBids;
+    type TreasuryModuleId = TreasuryModuleId;
 }
 
 parameter_types! {
     pub CreateClassDeposit: Balance = 2;
     pub CreateAssetDeposit: Balance = 1;
+    pub CreateGroupDeposit: Balance = 5;
+    pub MaxMintDeposit: Balance = 1000;
+    pub MaxRoyaltyFee: Perbill = Perbill::from_percent(25);
+    pub TransferEquippedWithAvatar: bool = true;
+    pub MaxSupporters: u32 = 2;
+    pub MaxBatchMint: u32 = 3;
+    pub MaxBundleSize: u32 = 2;
+    pub MaxClassAttributes: u32 = 3;
+    pub MaxAttributeKeyLength: u32 = 8;
+    pub MaxAttributeValueLength: u32 = 8;
     pub NftModuleId: ModuleId = ModuleId(*b"bit/bNFT");
+    pub NftEscrowModuleId: ModuleId = ModuleId(*b"bit/nfte");
 }
 
 impl pallet_nft::Config for Runtime {
     type Event = Event;
     type CreateClassDeposit = CreateClassDeposit;
     type CreateAssetDeposit = CreateAssetDeposit;
+    type CreateGroupDeposit = CreateGroupDeposit;
+    type MaxMintDeposit = MaxMintDeposit;
+    type MaxRoyaltyFee = MaxRoyaltyFee;
+    type TransferEquippedWithAvatar = TransferEquippedWithAvatar;
+    type MaxSupporters = MaxSupporters;
+    type MaxBatchMint = MaxBatchMint;
+    type MaxBundleSize = MaxBundleSize;
+    type MaxClassAttributes = MaxClassAttributes;
+    type MaxAttributeKeyLength = MaxAttributeKeyLength;
+    type MaxAttributeValueLength = MaxAttributeValueLength;
+    type ContractExecutor = ();
+    type FungibleTokenCurrency = Tokens;
+    type CurrencyIdAllocator = MockCurrencyIdAllocator;
+    type AccountRecovery = ();
+    type OffchainSignature = MockSignature;
+    type OffchainPublic = MockSigner;
     type Currency = Balances;
     type ModuleId = NftModuleId;
+    type EscrowModuleId = NftEscrowModuleId;
     type WeightInfo = ();
     type AuctionHandler = MockAuctionManager;
     type AssetsHandler = NftAssetHandler;
 }
 
+parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: CurrencyId| -> u128 {
+        Default::default()
+    };
+}
+
+impl orml_tokens::Config for Runtime {
+    type Event = Event;
+    type Balance = u128;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+}
+
+pub struct MockCurrencyIdAllocator;
+
+impl CurrencyIdAllocator for MockCurrencyIdAllocator {
+    fn allocate_currency_id() -> Result<CurrencyId, DispatchError> {
+        Ok(1)
+    }
+}
+
 impl orml_nft::Config for Runtime {
     type ClassId = u32;
     type TokenId = u64;
-    type ClassData = pallet_nft::NftClassData<Balance>;
+    type ClassData = pallet_nft::NftClassData<Balance, AccountId>;
     type TokenData = pallet_nft::NftAssetData<Balance>;
 }
 
@@ -151,6 +215,7 @@ construct_runtime!(
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         NFTModule: pallet_nft::{Module, Storage ,Call, Event<T>},
         OrmlNft: orml_nft::{Module, Storage, Config<T>},
+        Tokens: orml_tokens::{Module, Storage, Event<T>, Config<T>},
         NftAuctionModule: auction::{Module, Call, Storage, Event<T>},
 	}
 );
@@ -218,7 +283,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
         todo!()
     }
 
-    fn create_auction(auction_type: AuctionType, item_id: ItemId, end: Option<u64>, recipient: u128, initial_amount: Self::Balance, start: u64) -> Result<u64, DispatchError> {
+    fn create_auction(auction_type: AuctionType<Self::Balance>, item_id: ItemId, end: Option<u64>, recipient: u128, initial_amount: Self::Balance, start: u64) -> Result<u64, DispatchError> {
         todo!()
     }
 
@@ -234,3 +299,24 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
         todo!()
     }
 }
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+pub struct MockSigner(pub AccountId);
+
+impl IdentifyAccount for MockSigner {
+    type AccountId = AccountId;
+
+    fn into_account(self) -> AccountId {
+        self.0
+    }
+}
+
+impl Verify for MockSignature {
+    type Signer = MockSigner;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+        self.0 == *signer && msg.get() == &self.1[..]
+    }
+}
@@ -4,6 +4,8 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
 use pallet_nft::{TokenType, CollectionType};
+use sp_runtime::Perbill;
+use sp_core::H256;
 
 
 fn init_test_nft(owner: Origin) {
@@ -21,6 +23,10 @@ fn init_test_nft(owner: Origin) {
         COLLECTION_ID,
         TokenType::Transferable,
         CollectionType::Collectable,
+        None,
+        Perbill::zero(),
+        None,
+        None,
     ));
 
     assert_ok!(NFTModule::<Runtime>::mint(
@@ -29,7 +35,8 @@ fn init_test_nft(owner: Origin) {
         vec![1],
         vec![1],
         vec![1],
-        1
+        1,
+        vec![]
     ));    
 }
 
@@ -63,6 +70,10 @@ fn create_auction_fail() {
             COLLECTION_ID,
             TokenType::Transferable,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
 
         assert_ok!(NFTModule::<Runtime>::mint(
@@ -71,7 +82,8 @@ fn create_auction_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ));    
         //account does not have permission to create auction
         assert_noop!(NftAuctionModule::create_auction(AuctionType::Auction, ItemId::NFT(0), None, BOB, 100, 0
@@ -83,6 +95,10 @@ fn create_auction_fail() {
             COLLECTION_ID,
             TokenType::BoundToAddress,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
 
         assert_ok!(NFTModule::<Runtime>::mint(
@@ -91,7 +107,8 @@ fn create_auction_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ));    
         
         //Class is BoundToAddress
@@ -338,3 +355,459 @@ fn on_finalize_should_work() {
         assert_eq!(last_event(), event);
     });
 }
+
+#[test]
+fn buy_now_with_royalty_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let buyer = Origin::signed(ALICE);
+
+        assert_ok!(NFTModule::<Runtime>::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(NFTModule::<Runtime>::create_class(
+            owner.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            None,
+            Perbill::from_percent(10),
+            Some(CHARLIE),
+            None,
+        ));
+        assert_ok!(NFTModule::<Runtime>::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::BuyNow, ItemId::NFT(0), None, BOB, 150, 0));
+        assert_ok!(NftAuctionModule::buy_now(buyer.clone(), 0, 150));
+
+        assert_eq!(Balances::free_balance(ALICE), 99850);
+        assert_eq!(Balances::free_balance(CHARLIE), 15);
+        assert_eq!(Balances::free_balance(BOB), 632);
+
+        let royalty_event = mock::Event::auction(crate::Event::RoyaltyPaid(0, CHARLIE, 15));
+        assert!(System::events().iter().any(|record| record.event == royalty_event));
+    });
+}
+
+#[test]
+fn auction_with_royalty_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+
+        assert_ok!(NFTModule::<Runtime>::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(NFTModule::<Runtime>::create_class(
+            owner.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            None,
+            Perbill::from_percent(10),
+            Some(CHARLIE),
+            None,
+        ));
+        assert_ok!(NFTModule::<Runtime>::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Auction, ItemId::NFT(0), None, BOB, 100, 0));
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 200));
+        run_to_block(102);
+
+        assert_eq!(Balances::free_balance(ALICE), 99800);
+        assert_eq!(Balances::free_balance(CHARLIE), 20);
+        assert_eq!(Balances::free_balance(BOB), 677);
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+    });
+}
+
+#[test]
+fn buy_now_bundle_should_move_children() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let buyer = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+        assert_ok!(NFTModule::<Runtime>::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]));
+        assert_ok!(NFTModule::<Runtime>::bundle(owner.clone(), 0, vec![1, 2]));
+
+        assert_noop!(
+            NftAuctionModule::create_auction(AuctionType::BuyNow, ItemId::NFT(1), None, BOB, 150, 0),
+            pallet_nft::Error::<Runtime>::AssetIsBundled
+        );
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::BuyNow, ItemId::NFT(0), None, BOB, 150, 0));
+        assert_ok!(NftAuctionModule::buy_now(buyer.clone(), 0, 150));
+
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+        assert_ok!(NFTModule::<Runtime>::unbundle(buyer, 0));
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0, 1, 2]);
+    });
+}
+
+#[test]
+fn late_bid_should_extend_auction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Auction, ItemId::NFT(0), None, BOB, 100, 0));
+
+        // Bids outside the window leave the end untouched
+        assert_ok!(NftAuctionModule::bid(bidder.clone(), 0, 150));
+        assert_eq!(NftAuctionModule::auctions(0).unwrap().end, Some(101));
+
+        System::set_block_number(95);
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 200));
+        assert_eq!(last_event(), Event::auction(crate::Event::AuctionExtended(0, 105)));
+        assert_eq!(NftAuctionModule::auctions(0).unwrap().end, Some(105));
+        assert_eq!(NftAuctionModule::get_auction_item(0).unwrap().end_time, 105);
+        assert_eq!(NftAuctionModule::auction_end_time(101, 0), None);
+        assert_eq!(NftAuctionModule::auction_end_time(105, 0), Some(()));
+
+        run_to_block(102);
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(BOB), [0]);
+
+        run_to_block(106);
+        assert_eq!(last_event(), Event::auction(crate::Event::AuctionFinalized(0, ALICE, 200)));
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+        assert_eq!(NftAuctionModule::auction_extension(0), 0);
+    });
+}
+
+#[test]
+fn auction_extension_should_be_capped() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Auction, ItemId::NFT(0), None, BOB, 100, 0));
+
+        System::set_block_number(100);
+        assert_ok!(NftAuctionModule::bid(bidder.clone(), 0, 150));
+        assert_eq!(NftAuctionModule::auctions(0).unwrap().end, Some(110));
+
+        System::set_block_number(109);
+        assert_ok!(NftAuctionModule::bid(bidder.clone(), 0, 200));
+        assert_eq!(NftAuctionModule::auctions(0).unwrap().end, Some(116));
+        assert_eq!(NftAuctionModule::auction_extension(0), 15);
+
+        System::set_block_number(115);
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 250));
+        assert_eq!(last_event(), Event::auction(crate::Event::Bid(0, ALICE, 250)));
+        assert_eq!(NftAuctionModule::auctions(0).unwrap().end, Some(116));
+    });
+}
+
+#[test]
+fn dutch_auction_price_should_decay() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        init_test_nft(owner.clone());
+        assert_ok!(NFTModule::<Runtime>::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+
+        let linear = DutchAuctionConfig { floor_price: 500, decay: PriceDecay::Linear };
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Dutch(linear), ItemId::NFT(0), None, BOB, 1000, 0));
+        assert_ok!(NftAuctionModule::create_new_dutch_auction(
+            owner,
+            ItemId::NFT(1),
+            1000,
+            300,
+            PriceDecay::Stepwise { interval: 10, amount: 100 }
+        ));
+
+        assert_eq!(NftAuctionModule::dutch_auction_price(0, 1), Some(1000));
+        assert_eq!(NftAuctionModule::dutch_auction_price(0, 51), Some(750));
+        assert_eq!(NftAuctionModule::dutch_auction_price(0, 101), Some(500));
+
+        assert_eq!(NftAuctionModule::dutch_auction_price(1, 10), Some(1000));
+        assert_eq!(NftAuctionModule::dutch_auction_price(1, 31), Some(700));
+        assert_eq!(NftAuctionModule::dutch_auction_price(1, 91), Some(300));
+    });
+}
+
+#[test]
+fn dutch_auction_first_buyer_should_win() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let buyer = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+
+        let linear = DutchAuctionConfig { floor_price: 500, decay: PriceDecay::Linear };
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Dutch(linear), ItemId::NFT(0), None, BOB, 1000, 0));
+        assert_noop!(NftAuctionModule::bid(buyer.clone(), 0, 1000), Error::<Runtime>::InvalidAuctionType);
+
+        System::set_block_number(51);
+        assert_noop!(NftAuctionModule::buy_now(buyer.clone(), 0, 700), Error::<Runtime>::InvalidBuyItNowPrice);
+        assert_ok!(NftAuctionModule::buy_now(buyer.clone(), 0, 800));
+        assert_eq!(last_event(), Event::auction(crate::Event::BuyNowFinalised(0, ALICE, 750)));
+
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+        assert_eq!(Balances::free_balance(ALICE), 99250);
+        assert_eq!(Balances::free_balance(BOB), 1247);
+        assert_noop!(NftAuctionModule::buy_now(Origin::signed(CHARLIE), 0, 800), Error::<Runtime>::AuctionNotExist);
+    });
+}
+
+#[test]
+fn dutch_auction_should_work_for_spot() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stepwise = DutchAuctionConfig { floor_price: 100, decay: PriceDecay::Stepwise { interval: 10, amount: 50 } };
+        assert_ok!(NftAuctionModule::create_auction(AuctionType::Dutch(stepwise), ItemId::Spot(0, 0), None, BOB, 300, 0));
+
+        System::set_block_number(21);
+        assert_ok!(NftAuctionModule::buy_now(Origin::signed(ALICE), 0, 300));
+        assert_eq!(last_event(), Event::auction(crate::Event::BuyNowFinalised(0, ALICE, 200)));
+        assert_eq!(Balances::free_balance(BOB), 700);
+    });
+}
+
+#[test]
+fn dutch_auction_invalid_config_should_fail() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        init_test_nft(owner.clone());
+
+        let floor_above_start = DutchAuctionConfig { floor_price: 2000, decay: PriceDecay::Linear };
+        assert_noop!(
+            NftAuctionModule::create_auction(AuctionType::Dutch(floor_above_start), ItemId::NFT(0), None, BOB, 1000, 0),
+            Error::<Runtime>::InvalidDutchAuctionConfig
+        );
+        assert_noop!(
+            NftAuctionModule::create_new_dutch_auction(owner, ItemId::NFT(0), 1000, 500, PriceDecay::Stepwise { interval: 0, amount: 100 }),
+            Error::<Runtime>::InvalidDutchAuctionConfig
+        );
+    });
+}
+
+#[test]
+fn visible_reserve_and_increment_should_be_enforced() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+
+        assert_ok!(NftAuctionModule::create_new_auction(
+            owner,
+            ItemId::NFT(0),
+            100,
+            Some(ReservePrice { amount: 500, visible: true }),
+            Some(BidIncrement::Absolute(50))
+        ));
+
+        assert_noop!(NftAuctionModule::bid(bidder.clone(), 0, 400), Error::<Runtime>::BidBelowReservePrice);
+        assert_ok!(NftAuctionModule::bid(bidder.clone(), 0, 500));
+        assert_noop!(NftAuctionModule::bid(bidder.clone(), 0, 540), Error::<Runtime>::BidIncrementTooLow);
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 550));
+        assert_eq!(Balances::reserved_balance(ALICE), 550);
+    });
+}
+
+#[test]
+fn percentage_increment_should_be_enforced() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+
+        assert_ok!(NftAuctionModule::create_new_auction(
+            owner,
+            ItemId::NFT(0),
+            100,
+            None,
+            Some(BidIncrement::Percentage(Perbill::from_percent(10)))
+        ));
+
+        assert_ok!(NftAuctionModule::bid(bidder.clone(), 0, 200));
+        assert_noop!(NftAuctionModule::bid(bidder.clone(), 0, 219), Error::<Runtime>::BidIncrementTooLow);
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 220));
+    });
+}
+
+#[test]
+fn hidden_reserve_not_met_should_return_item() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let bidder = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+
+        assert_ok!(NftAuctionModule::create_new_auction(
+            owner,
+            ItemId::NFT(0),
+            100,
+            Some(ReservePrice { amount: 500, visible: false }),
+            None
+        ));
+
+        assert_ok!(NftAuctionModule::bid(bidder, 0, 300));
+        assert_eq!(Balances::reserved_balance(ALICE), 300);
+
+        run_to_block(102);
+        assert_eq!(last_event(), Event::auction(crate::Event::ReserveNotMet(0, ALICE, 300)));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 100000);
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(BOB), [0]);
+        assert_eq!(NftAuctionModule::assets_in_auction(0), None);
+        assert_eq!(NftAuctionModule::auction_bid_rules(0), None);
+    });
+}
+
+#[test]
+fn cancel_auction_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_new_auction(owner.clone(), ItemId::NFT(0), 100, None, None));
+
+        assert_noop!(NftAuctionModule::cancel_auction(Origin::signed(ALICE), 0), Error::<Runtime>::NoPermissionToUpdateAuction);
+        assert_ok!(NftAuctionModule::cancel_auction(owner.clone(), 0));
+        assert_eq!(last_event(), Event::auction(crate::Event::AuctionCancelled(0)));
+
+        assert_eq!(NftAuctionModule::auctions(0), None);
+        assert_eq!(NftAuctionModule::get_auction_item(0), None);
+        assert_eq!(NftAuctionModule::auction_end_time(101, 0), None);
+        assert_eq!(NftAuctionModule::assets_in_auction(0), None);
+
+        // Asset can be listed again straight away
+        assert_ok!(NftAuctionModule::create_new_buy_now(owner, ItemId::NFT(0), 150));
+        assert_noop!(NftAuctionModule::cancel_auction(Origin::signed(BOB), 0), Error::<Runtime>::AuctionNotExist);
+    });
+}
+
+#[test]
+fn cancel_auction_with_bid_should_fail() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_new_auction(owner.clone(), ItemId::NFT(0), 100, None, None));
+        assert_ok!(NftAuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+        assert_noop!(NftAuctionModule::cancel_auction(owner, 0), Error::<Runtime>::AuctionHasBids);
+        assert_eq!(NftAuctionModule::assets_in_auction(0), Some(true));
+    });
+}
+
+#[test]
+fn update_buy_now_price_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let buyer = Origin::signed(ALICE);
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_new_buy_now(owner.clone(), ItemId::NFT(0), 150));
+
+        assert_noop!(NftAuctionModule::update_buy_now_price(buyer.clone(), 0, 100), Error::<Runtime>::NoPermissionToUpdateAuction);
+        assert_noop!(NftAuctionModule::update_buy_now_price(owner.clone(), 0, 0), Error::<Runtime>::InvalidBuyItNowPrice);
+        assert_ok!(NftAuctionModule::update_buy_now_price(owner.clone(), 0, 100));
+        assert_eq!(last_event(), Event::auction(crate::Event::BuyNowPriceUpdated(0, 100)));
+
+        assert_noop!(NftAuctionModule::buy_now(buyer.clone(), 0, 150), Error::<Runtime>::InvalidBuyItNowPrice);
+        assert_ok!(NftAuctionModule::buy_now(buyer, 0, 100));
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+        assert_noop!(NftAuctionModule::update_buy_now_price(owner.clone(), 0, 120), Error::<Runtime>::AuctionNotExist);
+
+        assert_ok!(NFTModule::<Runtime>::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+        assert_ok!(NftAuctionModule::create_new_auction(owner.clone(), ItemId::NFT(1), 100, None, None));
+        assert_noop!(NftAuctionModule::update_buy_now_price(owner, 1, 120), Error::<Runtime>::InvalidAuctionType);
+    });
+}
+
+#[test]
+fn sealed_bid_second_price_auction_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let dave: AccountId = 4;
+        let salt = H256::repeat_byte(1);
+        Balances::make_free_balance_be(&CHARLIE, 1000);
+        Balances::make_free_balance_be(&dave, 1000);
+        init_test_nft(owner.clone());
+
+        assert_ok!(NftAuctionModule::create_new_sealed_bid_auction(owner, ItemId::NFT(0), 100, SealedBidSettlement::SecondPrice));
+
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, NftAuctionModule::sealed_bid_commitment(0, &ALICE, 400, salt), 500));
+        assert_eq!(last_event(), Event::auction(crate::Event::SealedBidCommitted(0, ALICE, 500)));
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(CHARLIE), 0, NftAuctionModule::sealed_bid_commitment(0, &CHARLIE, 300, salt), 300));
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(dave), 0, NftAuctionModule::sealed_bid_commitment(0, &dave, 1000, salt), 50));
+        assert_eq!(Balances::reserved_balance(ALICE), 500);
+        assert_eq!(NftAuctionModule::sealed_bid_count(0), 3);
+
+        System::set_block_number(80);
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 400, salt), Error::<Runtime>::SealedBidNotInRevealPhase);
+
+        System::set_block_number(81);
+        assert_noop!(
+            NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, NftAuctionModule::sealed_bid_commitment(0, &ALICE, 400, salt), 500),
+            Error::<Runtime>::SealedBidCommitPhaseOver
+        );
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 450, salt), Error::<Runtime>::InvalidSealedBidReveal);
+        assert_ok!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 400, salt));
+        assert_eq!(last_event(), Event::auction(crate::Event::SealedBidRevealed(0, ALICE, 400)));
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 400, salt), Error::<Runtime>::SealedBidAlreadyRevealed);
+        assert_ok!(NftAuctionModule::reveal_sealed_bid(Origin::signed(CHARLIE), 0, 300, salt));
+
+        run_to_block(102);
+        assert_eq!(last_event(), Event::auction(crate::Event::AuctionFinalized(0, ALICE, 300)));
+
+        let treasury: AccountId = TreasuryModuleId::get().into_account();
+        assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+        assert_eq!(Balances::free_balance(ALICE), 99700);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(CHARLIE), 1000);
+        assert_eq!(Balances::free_balance(dave), 950);
+        assert_eq!(Balances::reserved_balance(dave), 0);
+        assert_eq!(Balances::free_balance(treasury), 50);
+        assert_eq!(Balances::free_balance(BOB), 797);
+        assert_eq!(NftAuctionModule::sealed_bids(0, ALICE), None);
+        assert_eq!(NftAuctionModule::sealed_bid_count(0), 0);
+    });
+}
+
+#[test]
+fn sealed_bid_first_price_auction_should_work_for_spot() {
+    ExtBuilder::default().build().execute_with(|| {
+        let salt = H256::repeat_byte(2);
+        assert_ok!(NftAuctionModule::create_auction(
+            AuctionType::SealedBid(SealedBidSettlement::FirstPrice),
+            ItemId::Spot(0, 0),
+            None,
+            BOB,
+            100,
+            0
+        ));
+
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, NftAuctionModule::sealed_bid_commitment(0, &ALICE, 250, salt), 300));
+        System::set_block_number(90);
+        assert_ok!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 250, salt));
+
+        run_to_block(102);
+        assert_eq!(last_event(), Event::auction(crate::Event::AuctionFinalized(0, ALICE, 250)));
+        assert_eq!(Balances::free_balance(ALICE), 99750);
+        assert_eq!(Balances::free_balance(BOB), 750);
+    });
+}
+
+#[test]
+fn sealed_bid_commit_and_reveal_should_fail() {
+    ExtBuilder::default().build().execute_with(|| {
+        let owner = Origin::signed(BOB);
+        let salt = H256::repeat_byte(3);
+        Balances::make_free_balance_be(&CHARLIE, 1000);
+        Balances::make_free_balance_be(&4, 1000);
+        init_test_nft(owner.clone());
+        assert_ok!(NftAuctionModule::create_new_sealed_bid_auction(owner.clone(), ItemId::NFT(0), 100, SealedBidSettlement::FirstPrice));
+
+        let commitment = NftAuctionModule::sealed_bid_commitment(0, &ALICE, 600, salt);
+        assert_noop!(NftAuctionModule::bid(Origin::signed(ALICE), 0, 600), Error::<Runtime>::InvalidAuctionType);
+        assert_noop!(NftAuctionModule::commit_sealed_bid(owner.clone(), 0, commitment, 600), Error::<Runtime>::SelfBidNotAccepted);
+        assert_noop!(NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, commitment, 5), Error::<Runtime>::SealedBidDepositTooLow);
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, commitment, 500));
+        assert_noop!(NftAuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, commitment, 500), Error::<Runtime>::SealedBidAlreadyCommitted);
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(CHARLIE), 0, commitment, 10));
+        assert_ok!(NftAuctionModule::commit_sealed_bid(Origin::signed(4), 0, commitment, 10));
+        assert_noop!(NftAuctionModule::commit_sealed_bid(Origin::signed(5), 0, commitment, 10), Error::<Runtime>::TooManySealedBids);
+        assert_noop!(NftAuctionModule::cancel_auction(owner, 0), Error::<Runtime>::AuctionHasBids);
+
+        System::set_block_number(81);
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 600, salt), Error::<Runtime>::SealedBidExceedsDeposit);
+        // CHARLIE copied ALICE's commitment, which only opens for ALICE
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(CHARLIE), 0, 600, salt), Error::<Runtime>::InvalidSealedBidReveal);
+        assert_noop!(NftAuctionModule::reveal_sealed_bid(Origin::signed(5), 0, 600, salt), Error::<Runtime>::SealedBidNotFound);
+    });
+}
@@ -0,0 +1,32 @@
+//! Runtime API definition for the nft pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use pallet_nft::{NftAssetDetails, NftClassDetails, NftGroupCollectionData};
+use primitives::{AssetId, GroupCollectionId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait NftApi<AccountId, ClassId, TokenId, Balance> where
+        AccountId: Codec,
+        ClassId: Codec,
+        TokenId: Codec,
+        Balance: Codec,
+    {
+        /// Assets owned by `owner`, skipping the first `start` and returning at most `limit`, capped at 100.
+        /// Reads the owner's full asset list on every call
+        fn assets_by_owner(owner: AccountId, start: u32, limit: u32) -> Vec<AssetId>;
+
+        /// Class, token, data and auction status of an asset
+        fn asset_details(asset_id: AssetId) -> Option<NftAssetDetails<AccountId, ClassId, TokenId, Balance>>;
+
+        /// Owner, issuance and data of a class
+        fn class_details(class_id: ClassId) -> Option<NftClassDetails<AccountId, TokenId, Balance>>;
+
+        /// Group collection the class belongs to
+        fn class_group_collection(class_id: ClassId) -> Option<(GroupCollectionId, NftGroupCollectionData)>;
+    }
+}
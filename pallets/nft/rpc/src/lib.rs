@@ -0,0 +1,132 @@
+//! RPC interface for the nft pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_nft::{NftAssetDetails, NftClassDetails, NftGroupCollectionData};
+use primitives::{AssetId, GroupCollectionId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_nft_rpc_runtime_api::NftApi as NftRuntimeApi;
+
+#[rpc]
+pub trait NftApi<BlockHash, AccountId, ClassId, TokenId, Balance> {
+    #[rpc(name = "nft_getAssetsByOwner")]
+    fn assets_by_owner(
+        &self,
+        owner: AccountId,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<AssetId>>;
+
+    #[rpc(name = "nft_getAssetDetails")]
+    fn asset_details(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<NftAssetDetails<AccountId, ClassId, TokenId, Balance>>>;
+
+    #[rpc(name = "nft_getClassDetails")]
+    fn class_details(
+        &self,
+        class_id: ClassId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<NftClassDetails<AccountId, TokenId, Balance>>>;
+
+    #[rpc(name = "nft_getClassGroupCollection")]
+    fn class_group_collection(
+        &self,
+        class_id: ClassId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<(GroupCollectionId, NftGroupCollectionData)>>;
+}
+
+/// A struct that implements the [`NftApi`].
+pub struct Nft<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Nft<C, B> {
+    /// Create new `Nft` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error code for runtime api failures.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error<E: std::fmt::Debug>(e: E) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query nft data.".into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+impl<C, Block, AccountId, ClassId, TokenId, Balance> NftApi<<Block as BlockT>::Hash, AccountId, ClassId, TokenId, Balance>
+for Nft<C, Block>
+    where
+        Block: BlockT,
+        C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+        C::Api: NftRuntimeApi<Block, AccountId, ClassId, TokenId, Balance>,
+        AccountId: Codec,
+        ClassId: Codec,
+        TokenId: Codec,
+        Balance: Codec,
+{
+    fn assets_by_owner(
+        &self,
+        owner: AccountId,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<AssetId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.assets_by_owner(&at, owner, start, limit).map_err(runtime_error)
+    }
+
+    fn asset_details(
+        &self,
+        asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<NftAssetDetails<AccountId, ClassId, TokenId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.asset_details(&at, asset_id).map_err(runtime_error)
+    }
+
+    fn class_details(
+        &self,
+        class_id: ClassId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<NftClassDetails<AccountId, TokenId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.class_details(&at, class_id).map_err(runtime_error)
+    }
+
+    fn class_group_collection(
+        &self,
+        class_id: ClassId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<(GroupCollectionId, NftGroupCollectionData)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.class_group_collection(&at, class_id).map_err(runtime_error)
+    }
+}
@@ -7,21 +7,26 @@
 use codec::{Decode, Encode};
 use frame_support::{
     ensure, dispatch::{DispatchResult, DispatchResultWithPostInfo},
-    traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
+    traits::{Currency, ExistenceRequirement, GenesisBuild, Get, ReservableCurrency},
     pallet_prelude::*,
+    transactional,
 };
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
 use frame_system::pallet_prelude::*;
 use orml_nft::Pallet as NftModule;
-use primitives::{AssetId, GroupCollectionId};
+use primitives::{AssetId, Balance, CurrencyId, CurrencyIdAllocator, GroupCollectionId};
+use primitives::attributes::{AttributeType, AttributeValue, NftAttributes};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use sp_runtime::RuntimeDebug;
 use sp_runtime::{
-    traits::{AccountIdConversion, One},
-    DispatchError, ModuleId,
+    traits::{AccountIdConversion, CheckedAdd, CheckedMul, IdentifyAccount, One, SaturatedConversion, Verify, Zero},
+    DispatchError, ModuleId, Perbill,
 };
 use sp_std::vec::Vec;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
 use auction_manager::{Auction};
 
 #[cfg(test)]
@@ -36,6 +41,7 @@ pub mod default_weight;
 pub use default_weight::WeightInfo;
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct NftGroupCollectionData {
     pub name: Vec<u8>,
     // Metadata from ipfs
@@ -44,17 +50,48 @@ pub struct NftGroupCollectionData {
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftClassData<Balance> {
+pub struct NftClassData<Balance, AccountId> {
     //Minimum balance to create a collection of Asset
     pub deposit: Balance,
+    //Deposit charged for every token minted in this class
+    pub mint_deposit: Balance,
     // Metadata from ipfs
     pub metadata: Vec<u8>,
     pub token_type: TokenType,
     pub collection_type: CollectionType,
+    // Number of tokens minted in this class
+    pub total_supply: u64,
+    // Maximum supply declared at class creation, None for unlimited
+    pub initial_supply: Option<u64>,
+    // Share of every auction or buy now sale paid to the royalty beneficiary
+    pub royalty_fee: Perbill,
+    pub royalty_beneficiary: AccountId,
+}
+
+/// Class data layout before supply caps, royalties and creator mint deposits
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct OldNftClassData<Balance> {
+    pub deposit: Balance,
+    pub metadata: Vec<u8>,
+    pub token_type: TokenType,
+    pub collection_type: CollectionType,
     pub total_supply: u64,
     pub initial_supply: u64,
 }
 
+/// Storage layout versions of the nft pallet
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    V1_0_0,
+    V2_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
+
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct NftAssetData<Balance> {
@@ -125,6 +162,112 @@ impl Default for CollectionType {
     }
 }
 
+/// Asset details returned by the runtime api
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftAssetDetails<AccountId, ClassId, TokenId, Balance> {
+    pub owner: AccountId,
+    pub class_id: ClassId,
+    pub token_id: TokenId,
+    pub metadata: Vec<u8>,
+    pub data: NftAssetData<Balance>,
+    pub in_auction: bool,
+}
+
+/// Class details returned by the runtime api
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftClassDetails<AccountId, TokenId, Balance> {
+    pub owner: AccountId,
+    pub metadata: Vec<u8>,
+    pub total_issuance: TokenId,
+    pub data: NftClassData<Balance, AccountId>,
+    pub group_collection_id: Option<GroupCollectionId>,
+}
+
+/// Shares issued for a fractionalized asset
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FractionalAssetInfo {
+    pub currency_id: CurrencyId,
+    pub total_shares: Balance,
+}
+
+/// Rental proposed by the owner, waiting for the borrower to accept
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct RentalOffer<AccountId, BlockNumber> {
+    pub lender: AccountId,
+    pub borrower: AccountId,
+    pub duration: BlockNumber,
+    pub price: Option<(CurrencyId, Balance)>,
+}
+
+/// Active rental, the user may use the asset until the expiry block
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct NftRentalInfo<AccountId, BlockNumber> {
+    pub user: AccountId,
+    pub expires_at: BlockNumber,
+}
+
+/// Most assets returned by one assets_by_owner page
+pub const MAX_ASSETS_PAGE_SIZE: u32 = 100;
+
+/// Domain tag prepended to vouchers before signing
+pub const VOUCHER_SIGNING_DOMAIN: &[u8] = b"bcnft/voucher";
+
+/// Mint authorization signed off-chain by the class owner
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct MintVoucher<ClassId, Balance, BlockNumber> {
+    pub class_id: ClassId,
+    pub name: Vec<u8>,
+    pub description: Vec<u8>,
+    pub metadata: Vec<u8>,
+    pub price: Balance,
+    pub expiry: BlockNumber,
+    pub nonce: u64,
+}
+
+/// Accounts allowed to buy before the public phase
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum SaleAllowlist<AccountId> {
+    Accounts(Vec<AccountId>),
+    // Root of a merkle tree of blake2_256 hashed encoded account ids, pairs hashed in sorted order
+    MerkleRoot(H256),
+}
+
+/// Primary sale of a class, minted on purchase
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct SaleConfig<AccountId, BlockNumber> {
+    pub currency_id: CurrencyId,
+    pub price: Balance,
+    pub name: Vec<u8>,
+    pub description: Vec<u8>,
+    pub metadata: Vec<u8>,
+    // Allowlist phase starts here, public phase starts at public_start
+    pub start: BlockNumber,
+    pub public_start: BlockNumber,
+    pub end: BlockNumber,
+    pub max_per_account: u32,
+    pub allowlist: Option<SaleAllowlist<AccountId>>,
+}
+
+/// Avatar slots a wearable can be equipped on
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum WearableSlot {
+    Hat,
+    Jacket,
+    Pants,
+    Shoes,
+}
+
+/// Class or token whose metadata is updated or frozen
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum MetadataTarget<ClassId> {
+    Class(ClassId),
+    Token(AssetId),
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -139,21 +282,70 @@ pub mod pallet {
     frame_system::Config +
     orml_nft::Config<
         TokenData=NftAssetData<BalanceOf<Self>>,
-        ClassData=NftClassData<BalanceOf<Self>>,
+        ClassData=NftClassData<BalanceOf<Self>, <Self as frame_system::Config>::AccountId>,
     >
     {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-        /// The minimum balance to create class
+        /// The default balance to create class, governance can override it
         #[pallet::constant]
         type CreateClassDeposit: Get<BalanceOf<Self>>;
-        /// The minimum balance to create token
+        /// The default minimum balance to create token, governance can override it
         #[pallet::constant]
         type CreateAssetDeposit: Get<BalanceOf<Self>>;
+        /// The balance reserved to create group collection
+        #[pallet::constant]
+        type CreateGroupDeposit: Get<BalanceOf<Self>>;
+        /// The highest per token deposit a class creator can ask for
+        #[pallet::constant]
+        type MaxMintDeposit: Get<BalanceOf<Self>>;
+        /// The maximum royalty fee a class creator can charge on resale
+        #[pallet::constant]
+        type MaxRoyaltyFee: Get<Perbill>;
+        /// Move equipped wearables with the avatar on transfer, otherwise they must be unequipped first
+        #[pallet::constant]
+        type TransferEquippedWithAvatar: Get<bool>;
+        /// The maximum number of attributes in a class schema
+        #[pallet::constant]
+        type MaxClassAttributes: Get<u32>;
+        /// The maximum length of an attribute key
+        #[pallet::constant]
+        type MaxAttributeKeyLength: Get<u32>;
+        /// The maximum length of a string attribute value or enum variant name
+        #[pallet::constant]
+        type MaxAttributeValueLength: Get<u32>;
+        /// The maximum number of child assets in a bundle
+        #[pallet::constant]
+        type MaxBundleSize: Get<u32>;
+        /// The maximum number of tokens minted in one mint_batch call
+        #[pallet::constant]
+        type MaxBatchMint: Get<u32>;
+        /// The maximum number of accounts that can sign a single asset
+        #[pallet::constant]
+        type MaxSupporters: Get<u32>;
+        /// Contract executor for executable NFT
+        type ContractExecutor: ContractExecutor<Self::AccountId>;
+        /// Multi currency used to issue shares of fractionalized NFT and to pay rentals
+        type FungibleTokenCurrency: MultiCurrencyExtended<
+            Self::AccountId,
+            CurrencyId=CurrencyId,
+            Balance=Balance,
+        >;
+        /// Currency id source for shares of fractionalized NFT
+        type CurrencyIdAllocator: CurrencyIdAllocator;
+        /// Account recovery used to reissue soulbound NFT of a lost account
+        type AccountRecovery: AccountRecovery<Self::AccountId>;
+        /// Signature of the class owner over a mint voucher
+        type OffchainSignature: Verify<Signer=Self::OffchainPublic> + Parameter;
+        /// Public key of the voucher signer, identifies the class owner account
+        type OffchainPublic: IdentifyAccount<AccountId=Self::AccountId>;
         // Currency type for reserve/unreserve balance
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
         //NFT Module Id
         #[pallet::constant]
         type ModuleId: Get<ModuleId>;
+        /// Module id of the fractional vault and bundle escrow accounts, kept apart from class funds
+        #[pallet::constant]
+        type EscrowModuleId: Get<ModuleId>;
         // Weight info
         type WeightInfo: WeightInfo;
         /// Auction Handler
@@ -161,9 +353,9 @@ pub mod pallet {
         type AssetsHandler: AssetHandler;
     }
 
-    type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
-    type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
-    type BalanceOf<T> =
+    pub(super) type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
+    pub(super) type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
+    pub(super) type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::storage]
@@ -186,6 +378,36 @@ pub mod pallet {
     pub(super) type ClassDataCollection<T: Config> =
     StorageMap<_, Blake2_128Concat, ClassIdOf<T>, GroupCollectionId, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn get_group_collection_owner)]
+    /// Owner and reserved deposit of groups created by signed accounts
+    pub(super) type GroupCollectionOwners<T: Config> =
+    StorageMap<_, Blake2_128Concat, GroupCollectionId, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_class_depositor)]
+    /// Account that paid the class deposit, refunded when the class is destroyed
+    pub(super) type ClassDepositors<T: Config> =
+    StorageMap<_, Blake2_128Concat, ClassIdOf<T>, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    /// Classes in each group, reverse of ClassDataCollection
+    pub(super) type GroupClasses<T: Config> =
+    StorageDoubleMap<_, Blake2_128Concat, GroupCollectionId, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
+
+    #[pallet::storage]
+    /// Class deposit set by governance, overrides CreateClassDeposit
+    pub(super) type ClassDepositMinimum<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    /// Minimum per token deposit set by governance, overrides CreateAssetDeposit
+    pub(super) type AssetDepositMinimum<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn storage_version)]
+    /// Storage layout version, used to run migrations once
+    pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn next_group_collection_id)]
     pub(super) type NextGroupCollectionId<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -198,10 +420,122 @@ pub mod pallet {
     #[pallet::getter(fn get_class_type)]
     pub(super) type ClassDataType<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, TokenType, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn is_metadata_frozen)]
+    /// Classes and tokens whose metadata can no longer be updated
+    pub(super) type FrozenMetadata<T: Config> = StorageMap<_, Blake2_128Concat, MetadataTarget<ClassIdOf<T>>, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_equipped_item)]
+    /// Wearable equipped on each slot of an avatar
+    pub(super) type EquippedItems<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, WearableSlot, AssetId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_equipped_avatar)]
+    /// Avatar a wearable is currently equipped on
+    pub(super) type EquippedOn<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, AssetId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_class_contract)]
+    /// Contract bound to an executable class
+    pub(super) type ClassContracts<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_approved)]
+    /// Account approved to transfer a single asset, cleared on every transfer
+    pub(super) type TokenApprovals<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_approved_for_all)]
+    /// Operators allowed to transfer every asset of an owner
+    pub(super) type OperatorApprovals<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_fractional_asset)]
+    /// Assets locked in the vault with their issued shares
+    pub(super) type FractionalAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, FractionalAssetInfo, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_rental_offer)]
+    /// Pending rental offers by asset
+    pub(super) type RentalOffers<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, RentalOffer<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_asset_user)]
+    /// Current user of a rented asset
+    pub(super) type AssetUsers<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, NftRentalInfo<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+    #[pallet::storage]
+    /// Rentals indexed by expiry block, cleared in on_initialize
+    pub(super) type RentalExpiry<T: Config> = StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, AssetId, (), OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_class_schema)]
+    /// Attribute keys and types declared by each class
+    pub(super) type ClassAttributeSchemas<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, Vec<(Vec<u8>, AttributeType)>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_token_attribute)]
+    /// Validated attribute values of each token
+    pub(super) type TokenAttributes<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, Vec<u8>, AttributeValue, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_class_sale)]
+    /// Primary sale configured by the class owner
+    pub(super) type ClassSales<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, SaleConfig<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_sale_purchases)]
+    /// Number of tokens bought by each account in a class sale
+    pub(super) type SalePurchases<T: Config> = StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_bundle_children)]
+    /// Child assets escrowed under a parent asset
+    pub(super) type BundleChildren<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Vec<AssetId>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_bundle_parent)]
+    /// Parent asset of each bundled child
+    pub(super) type BundledIn<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, AssetId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_supporters)]
+    /// Accounts that signed an asset, kept across transfers
+    pub(super) type Supporters<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn get_signing_fee)]
+    /// Fee the asset owner charges every signer, cleared on transfer
+    pub(super) type SigningFees<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BalanceOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_voucher_used)]
+    /// Voucher nonces already redeemed by class
+    pub(super) type UsedVoucherNonces<T: Config> = StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, u64, bool, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn next_asset_id)]
     pub(super) type NextAssetId<T: Config> = StorageValue<_, AssetId, ValueQuery>;
 
+    #[pallet::genesis_config]
+    pub struct GenesisConfig {}
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        fn default() -> Self {
+            GenesisConfig {}
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            //Chains starting from genesis already use the current class data layout
+            StorageVersion::<T>::put(Releases::V2_0_0);
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     #[pallet::metadata(
@@ -218,8 +552,74 @@ pub mod pallet {
         NewNftMinted(AssetId, AssetId, <T as frame_system::Config>::AccountId, ClassIdOf<T>, u32, TokenIdOf<T>),
         //Successfully transfer NFT
         TransferedNft(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, TokenIdOf<T>),
-        //Signed on NFT
-        SignedNft(TokenIdOf<T>, <T as frame_system::Config>::AccountId),
+        //Signed on NFT [asset, signer]
+        SignedNft(AssetId, <T as frame_system::Config>::AccountId),
+        //Successfully burn NFT and refund its deposit
+        BurnedNft(<T as frame_system::Config>::AccountId, AssetId, BalanceOf<T>),
+        //Successfully transfer NFT class
+        TransferedNftClass(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, ClassIdOf<T>),
+        //Successfully destroy NFT class and refund its deposit
+        DestroyedNftClass(<T as frame_system::Config>::AccountId, ClassIdOf<T>, BalanceOf<T>),
+        //Successfully update NFT metadata
+        TokenMetadataUpdated(<T as frame_system::Config>::AccountId, AssetId),
+        //Successfully update NFT class metadata
+        ClassMetadataUpdated(<T as frame_system::Config>::AccountId, ClassIdOf<T>),
+        //Metadata of class or token is frozen permanently
+        MetadataFrozen(<T as frame_system::Config>::AccountId, MetadataTarget<ClassIdOf<T>>),
+        //Wearable equipped on avatar [owner, avatar, slot, wearable]
+        WearableEquipped(<T as frame_system::Config>::AccountId, AssetId, WearableSlot, AssetId),
+        //Wearable unequipped from avatar [owner, avatar, slot, wearable]
+        WearableUnequipped(<T as frame_system::Config>::AccountId, AssetId, WearableSlot, AssetId),
+        //Contract bound to executable class [class, contract]
+        ContractBound(ClassIdOf<T>, <T as frame_system::Config>::AccountId),
+        //Executable NFT called its contract [owner, asset, contract]
+        NftExecuted(<T as frame_system::Config>::AccountId, AssetId, <T as frame_system::Config>::AccountId),
+        //Approved account to transfer asset [owner, spender, asset]
+        Approval(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, AssetId),
+        //Approval of asset cancelled [owner, asset]
+        ApprovalCancelled(<T as frame_system::Config>::AccountId, AssetId),
+        //Operator approval changed [owner, operator, approved]
+        ApprovalForAll(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, bool),
+        //Asset locked in the vault and shares issued [owner, asset, currency, total_shares]
+        NftFractionalized(<T as frame_system::Config>::AccountId, AssetId, CurrencyId, Balance),
+        //Shares burned and asset released from the vault [redeemer, asset, currency]
+        NftRedeemed(<T as frame_system::Config>::AccountId, AssetId, CurrencyId),
+        //Rental offered [owner, borrower, asset, duration]
+        RentalOffered(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, AssetId, T::BlockNumber),
+        //Rental offer withdrawn [owner, asset]
+        RentalOfferCancelled(<T as frame_system::Config>::AccountId, AssetId),
+        //Rental started [owner, user, asset, expires_at]
+        NftRented(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, AssetId, T::BlockNumber),
+        //Rental expired and user role cleared [user, asset]
+        RentalExpired(<T as frame_system::Config>::AccountId, AssetId),
+        //Soulbound asset burned by its issuer [issuer, holder, asset]
+        NftRevoked(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, AssetId),
+        //Soulbound asset moved to a new account [from, to, asset]
+        NftReissued(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId, AssetId),
+        //Voucher redeemed and asset minted to buyer [buyer, class, asset, nonce]
+        VoucherRedeemed(<T as frame_system::Config>::AccountId, ClassIdOf<T>, AssetId, u64),
+        //Group name or properties changed [group]
+        NftGroupCollectionUpdated(GroupCollectionId),
+        //Empty group removed and deposit released [group]
+        NftGroupCollectionRemoved(GroupCollectionId),
+        //Governance changed deposit minimums [class_deposit, asset_deposit]
+        DepositMinimumsUpdated(BalanceOf<T>, BalanceOf<T>),
+        //Class attribute schema declared [class]
+        ClassSchemaSet(ClassIdOf<T>),
+        //Token attributes changed by the class owner [class_owner, asset]
+        TokenAttributesUpdated(<T as frame_system::Config>::AccountId, AssetId),
+        //Primary sale configured [class]
+        SaleConfigured(ClassIdOf<T>),
+        //Primary sale removed [class]
+        SaleCancelled(ClassIdOf<T>),
+        //Tokens bought from primary sale [buyer, class, first_asset, last_asset, quantity]
+        SalePurchased(<T as frame_system::Config>::AccountId, ClassIdOf<T>, AssetId, AssetId, u32),
+        //Child assets escrowed under parent [owner, parent, children]
+        NftBundled(<T as frame_system::Config>::AccountId, AssetId, Vec<AssetId>),
+        //Bundle released to the parent owner [owner, parent]
+        NftUnbundled(<T as frame_system::Config>::AccountId, AssetId),
+        //Signing fee set or removed by the asset owner [owner, asset, fee]
+        SigningFeeUpdated(<T as frame_system::Config>::AccountId, AssetId, Option<BalanceOf<T>>),
     }
 
     #[pallet::error]
@@ -248,21 +648,112 @@ pub mod pallet {
         AssetIdAlreadyExist,
         //Asset Id is currently in an auction
         AssetAlreadyInAuction,
+        //Minting would exceed the max supply of the class
+        ExceededMaxSupply,
+        //Royalty fee is higher than the allowed maximum
+        RoyaltyFeeExceedLimit,
+        //Class still has tokens
+        CannotDestroyClass,
+        //Metadata is frozen and cannot be updated
+        MetadataIsFrozen,
+        //Asset is not a wearable
+        NotWearable,
+        //Asset cannot be used as an avatar
+        InvalidAvatar,
+        //Avatar slot already has a wearable equipped
+        SlotAlreadyOccupied,
+        //Avatar slot has no wearable equipped
+        SlotIsEmpty,
+        //Asset is equipped on an avatar
+        AssetIsEquipped,
+        //Avatar still has wearables equipped
+        AvatarHasEquippedItems,
+        //Class is not executable
+        NotExecutable,
+        //No contract bound to the class
+        ContractNotBound,
+        //Contract call failed or reverted
+        ContractExecutionFailed,
+        //Asset is locked in the fractional vault
+        AssetIsFractionalized,
+        //Asset is not fractionalized
+        AssetNotFractionalized,
+        //Redeemer does not hold every share
+        InsufficientShares,
+        //Asset is currently rented
+        AssetIsRented,
+        //Rental offer does not exist
+        RentalOfferNotFound,
+        //Rental duration must be greater than zero
+        InvalidRentalDuration,
+        //Asset is not a BoundToAddress token
+        NotBoundToAddress,
+        //Account already signed this asset
+        AlreadySigned,
+        //Group still has classes
+        GroupIsNotEmpty,
+        //Mint deposit is lower than the pallet minimum
+        DepositBelowMinimum,
+        //Asset is escrowed in a bundle
+        AssetIsBundled,
+        //Asset still holds bundled children
+        AssetHasBundle,
+        //Bundle has no children, too many, duplicates or contains the parent
+        InvalidBundle,
+        //Asset is not a bundle parent
+        BundleNotFound,
+        //Sale blocks are not ordered start <= public_start <= end
+        InvalidSaleConfig,
+        //Class has no primary sale
+        SaleNotFound,
+        //Sale has not started or already ended
+        SaleNotActive,
+        //Buyer is not on the allowlist during allowlist phase
+        NotInAllowlist,
+        //Buyer reached the per account limit
+        ExceededPurchaseLimit,
+        //Schema has too many keys, duplicated or too long keys, or empty enums
+        InvalidAttributeSchema,
+        //Schema cannot change once the class has tokens
+        SchemaInUse,
+        //Attribute key is not declared in the class schema
+        UnknownAttribute,
+        //Attribute value does not match the declared type
+        AttributeTypeMismatch,
+        //String attribute value is too long
+        AttributeValueTooLong,
+        //Too many items in one batch
+        ExceededMaxBatchMint,
+        //Recipients list length differs from items
+        RecipientsMismatch,
+        //Voucher signature does not match the class owner
+        InvalidVoucherSignature,
+        //Voucher expiry block has passed
+        VoucherExpired,
+        //Voucher nonce was already redeemed
+        VoucherAlreadyUsed,
+        //Asset reached the maximum number of supporters
+        ExceededMaxSupporters,
+        //Rental would end past the last representable block
+        RentalDurationOverflow,
+        //Mint deposit is above MaxMintDeposit
+        DepositAboveMaximum,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(10_000)]
+        #[transactional]
         pub fn create_group(origin: OriginFor<T>, name: Vec<u8>, properties: Vec<u8>) -> DispatchResultWithPostInfo {
-            ensure_root(origin)?;
-            let next_group_collection_id = Self::do_create_group_collection(name.clone(), properties.clone())?;
-
-            let collection_data = NftGroupCollectionData {
-                name,
-                properties,
-            };
-
-            GroupCollections::<T>::insert(next_group_collection_id, collection_data);
+            let owner = Self::ensure_signed_or_root(origin)?;
+            let next_group_collection_id = Self::do_create_group_collection(name, properties)?;
+
+            //Groups created by root are owned by governance and take no deposit
+            if let Some(owner) = owner {
+                let deposit = T::CreateGroupDeposit::get();
+                <T as Config>::Currency::reserve(&owner, deposit)?;
+                GroupCollectionOwners::<T>::insert(next_group_collection_id, (owner, deposit));
+            }
 
             let all_collection_count = Self::all_nft_collection_count();
             let new_all_nft_collection_count = all_collection_count.checked_add(One::one())
@@ -275,19 +766,78 @@ pub mod pallet {
         }
 
         #[pallet::weight(10_000)]
-        pub fn create_class(origin: OriginFor<T>, metadata: Vec<u8>, collection_id: GroupCollectionId, token_type: TokenType, collection_type: CollectionType) -> DispatchResultWithPostInfo {
+        pub fn update_group(origin: OriginFor<T>, group_id: GroupCollectionId, name: Vec<u8>, properties: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = Self::ensure_signed_or_root(origin)?;
+            Self::ensure_group_owner(sender, group_id)?;
+
+            GroupCollections::<T>::insert(group_id, NftGroupCollectionData {
+                name,
+                properties,
+            });
+
+            Self::deposit_event(Event::<T>::NftGroupCollectionUpdated(group_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn remove_group(origin: OriginFor<T>, group_id: GroupCollectionId) -> DispatchResultWithPostInfo {
+            let sender = Self::ensure_signed_or_root(origin)?;
+            Self::ensure_group_owner(sender, group_id)?;
+            ensure!(GroupClasses::<T>::iter_prefix(group_id).next().is_none(), Error::<T>::GroupIsNotEmpty);
+
+            GroupCollections::<T>::remove(group_id);
+            if let Some((owner, deposit)) = GroupCollectionOwners::<T>::take(group_id) {
+                <T as Config>::Currency::unreserve(&owner, deposit);
+            }
+            AllNftGroupCollection::<T>::mutate(|count| *count = count.saturating_sub(One::one()));
+
+            Self::deposit_event(Event::<T>::NftGroupCollectionRemoved(group_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_deposit_minimums(origin: OriginFor<T>, class_deposit: BalanceOf<T>, asset_deposit: BalanceOf<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(asset_deposit <= T::MaxMintDeposit::get(), Error::<T>::DepositAboveMaximum);
+
+            ClassDepositMinimum::<T>::put(class_deposit);
+            AssetDepositMinimum::<T>::put(asset_deposit);
+
+            Self::deposit_event(Event::<T>::DepositMinimumsUpdated(class_deposit, asset_deposit));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn create_class(origin: OriginFor<T>, metadata: Vec<u8>, collection_id: GroupCollectionId, token_type: TokenType, collection_type: CollectionType, max_supply: Option<u64>, royalty_fee: Perbill, royalty_beneficiary: Option<T::AccountId>, mint_deposit: Option<BalanceOf<T>>) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
             let next_class_id = NftModule::<T>::next_class_id();
+            if let Some(max_supply) = max_supply {
+                ensure!(max_supply > 0, Error::<T>::InvalidQuantity);
+            }
+            ensure!(royalty_fee <= T::MaxRoyaltyFee::get(), Error::<T>::RoyaltyFeeExceedLimit);
             // TODO 
             ensure!(
                 GroupCollections::<T>::contains_key(collection_id), 
                 Error::<T>::CollectionIsNotExist
             );
+            //Groups created by signed accounts only take classes from their owner, governance groups are open
+            if let Some((group_owner, _)) = GroupCollectionOwners::<T>::get(collection_id) {
+                ensure!(sender == group_owner, Error::<T>::NoPermission);
+            }
             //Class fund
             let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(next_class_id);
 
-            // Secure deposit of token class owner -- TODO - support customise deposit
-            let class_deposit = T::CreateClassDeposit::get();
+            // Creators may ask a higher deposit per token than the pallet minimum
+            let asset_deposit_minimum = Self::asset_deposit_minimum();
+            let mint_deposit = mint_deposit.unwrap_or(asset_deposit_minimum);
+            ensure!(mint_deposit >= asset_deposit_minimum, Error::<T>::DepositBelowMinimum);
+            ensure!(mint_deposit <= T::MaxMintDeposit::get(), Error::<T>::DepositAboveMaximum);
+
+            // Secure deposit of token class owner
+            let class_deposit = Self::class_deposit_minimum();
             // Transfer fund to pot
             <T as Config>::Currency::transfer(&sender, &class_fund, class_deposit, ExistenceRequirement::KeepAlive)?;
 
@@ -296,15 +846,20 @@ pub mod pallet {
             let class_data = NftClassData
             {
                 deposit: class_deposit,
+                mint_deposit,
                 token_type,
                 collection_type,
                 metadata: metadata.clone(),
                 total_supply: Default::default(),
-                initial_supply: Default::default(),
+                initial_supply: max_supply,
+                royalty_fee,
+                royalty_beneficiary: royalty_beneficiary.unwrap_or_else(|| sender.clone()),
             };
 
             NftModule::<T>::create_class(&sender, metadata, class_data)?;
+            ClassDepositors::<T>::insert(next_class_id, sender.clone());
             ClassDataCollection::<T>::insert(next_class_id, collection_id);
+            GroupClasses::<T>::insert(collection_id, next_class_id, ());
 
             Self::deposit_event(Event::<T>::NewNftClassCreated(sender, next_class_id));
 
@@ -313,16 +868,20 @@ pub mod pallet {
 
 
         #[pallet::weight(< T as Config >::WeightInfo::mint(* quantity))]
-        pub fn mint(origin: OriginFor<T>, class_id: ClassIdOf<T>, name: Vec<u8>, description: Vec<u8>, metadata: Vec<u8>, quantity: u32) -> DispatchResultWithPostInfo {
+        #[transactional]
+        pub fn mint(origin: OriginFor<T>, class_id: ClassIdOf<T>, name: Vec<u8>, description: Vec<u8>, metadata: Vec<u8>, quantity: u32, attributes: Vec<(Vec<u8>, AttributeValue)>) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
             ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
             let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
             ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            Self::validate_attributes(class_id, &attributes)?;
+
+            Self::increase_class_supply(class_id, quantity)?;
 
-            let deposit = T::CreateAssetDeposit::get();
+            let deposit = Self::mint_deposit_of(&class_info.data);
             let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(class_id);
-            let total_deposit = deposit * Into::<BalanceOf<T>>::into(quantity);
+            let total_deposit = deposit.checked_mul(&quantity.into()).ok_or(Error::<T>::InvalidQuantity)?;
 
             <T as Config>::Currency::transfer(&sender, &class_fund, total_deposit, ExistenceRequirement::KeepAlive)?;
             <T as Config>::Currency::reserve(&class_fund, total_deposit)?;
@@ -338,33 +897,62 @@ pub mod pallet {
             let mut last_token_id: TokenIdOf<T> = Default::default();
 
             for _ in 0..quantity {
-                let asset_id = NextAssetId::<T>::try_mutate(|id| -> Result<AssetId, DispatchError> {
-                    let current_id = *id;
-                    *id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAssetId)?;
+                let (asset_id, token_id) = Self::do_mint(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
+                for (key, value) in attributes.iter() {
+                    TokenAttributes::<T>::insert(asset_id, key, value);
+                }
+                new_asset_ids.push(asset_id);
+                last_token_id = token_id;
+            }
 
-                    Ok(current_id)
-                })?;
+            Self::deposit_event(Event::<T>::NewNftMinted(*new_asset_ids.first().unwrap(), *new_asset_ids.last().unwrap(), sender, class_id, quantity, last_token_id));
 
-                new_asset_ids.push(asset_id);
+            Ok(().into())
+        }
 
-                if AssetsByOwner::<T>::contains_key(&sender) {
-                    AssetsByOwner::<T>::try_mutate(
-                        &sender,
-                        |asset_ids| -> DispatchResult {
-                            // Check if the asset_id already in the owner
-                            ensure!(!asset_ids.iter().any(|i| asset_id == *i), Error::<T>::AssetIdAlreadyExist);
-                            asset_ids.push(asset_id);
-                            Ok(())
-                        },
-                    )?;
-                } else {
-                    let mut assets = Vec::<AssetId>::new();
-                    assets.push(asset_id);
-                    AssetsByOwner::<T>::insert(&sender, assets)
-                }
+        #[pallet::weight(< T as Config >::WeightInfo::mint(items.len() as u32))]
+        #[transactional]
+        pub fn mint_batch(
+            origin: OriginFor<T>,
+            class_id: ClassIdOf<T>,
+            items: Vec<(Vec<u8>, Vec<u8>, Vec<u8>)>,
+            recipients: Option<Vec<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let quantity = items.len() as u32;
+            ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+            ensure!(quantity <= T::MaxBatchMint::get(), Error::<T>::ExceededMaxBatchMint);
+            if let Some(recipients) = &recipients {
+                ensure!(recipients.len() == items.len(), Error::<T>::RecipientsMismatch);
+            }
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+            Self::increase_class_supply(class_id, quantity)?;
+
+            let deposit = Self::mint_deposit_of(&class_info.data);
+            let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(class_id);
+            let total_deposit = deposit.checked_mul(&quantity.into()).ok_or(Error::<T>::InvalidQuantity)?;
+
+            <T as Config>::Currency::transfer(&sender, &class_fund, total_deposit, ExistenceRequirement::KeepAlive)?;
+            <T as Config>::Currency::reserve(&class_fund, total_deposit)?;
 
-                let token_id = NftModule::<T>::mint(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
-                Assets::<T>::insert(asset_id, (class_id, token_id));
+            let mut new_asset_ids: Vec<AssetId> = Vec::new();
+            let mut last_token_id: TokenIdOf<T> = Default::default();
+
+            for (i, (name, description, metadata)) in items.into_iter().enumerate() {
+                let owner = recipients.as_ref().map_or(&sender, |recipients| &recipients[i]);
+                let new_nft_data = NftAssetData {
+                    deposit,
+                    name,
+                    description,
+                    properties: metadata.clone(),
+                };
+
+                let (asset_id, token_id) = Self::do_mint(owner, class_id, metadata, new_nft_data)?;
+                new_asset_ids.push(asset_id);
                 last_token_id = token_id;
             }
 
@@ -403,8 +991,7 @@ pub mod pallet {
                     TokenType::Transferable => {
                         let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
                         ensure!(owner.clone() == asset_info.owner, Error::<T>::NoPermission);
-                        Self::handle_asset_ownership_transfer(&owner, &item.0, item.1);
-                        NftModule::<T>::transfer(&owner, &item.0, (asset.0, asset.1))?;
+                        Self::do_transfer(&owner, &item.0, item.1)?;
                         Self::deposit_event(Event::<T>::TransferedNft(owner.clone(), item.0.clone(), asset.1.clone()));
                     }
                     _ => ()
@@ -413,79 +1000,972 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
-}
+        #[pallet::weight(10_000)]
+        pub fn burn(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
 
-impl<T: Config> Module<T> {
-    fn do_create_group_collection(
-        name: Vec<u8>,
-        properties: Vec<u8>,
-    ) -> Result<GroupCollectionId, DispatchError> {
-        let next_group_collection_id = NextGroupCollectionId::<T>::try_mutate(
-            |collection_id| -> Result<GroupCollectionId, DispatchError> {
-                let current_id = *collection_id;
+            ensure!(!T::AssetsHandler::check_item_in_auction(asset_id), Error::<T>::AssetAlreadyInAuction);
 
-                *collection_id = collection_id
-                    .checked_add(One::one())
-                    .ok_or(Error::<T>::NoAvailableCollectionId)?;
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
 
-                Ok(current_id)
-            },
-        )?;
+            let deposit = Self::do_burn(&sender, asset_id)?;
 
-        let collection_data = NftGroupCollectionData {
-            name,
-            properties,
-        };
+            Self::deposit_event(Event::<T>::BurnedNft(sender, asset_id, deposit));
 
-        <GroupCollections<T>>::insert(next_group_collection_id, collection_data);
+            Ok(().into())
+        }
 
-        Ok(next_group_collection_id)
-    }
+        #[pallet::weight(10_000)]
+        pub fn revoke(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
 
-    fn handle_asset_ownership_transfer(
-        sender: &T::AccountId,
-        to: &T::AccountId,
-        asset_id: AssetId,
-    ) -> DispatchResult {
-        //Remove asset from sender
-        AssetsByOwner::<T>::try_mutate(&sender, |asset_ids| -> DispatchResult {
-            // Check if the asset_id already in the owner
-            let asset_index = asset_ids.iter().position(|x| *x == asset_id).unwrap();
-            asset_ids.remove(asset_index);
+            let (holder, issuer) = Self::get_bound_asset_issuer(&asset_id)?;
+            ensure!(sender == issuer, Error::<T>::NoPermission);
 
-            Ok(())
-        })?;
+            //Deposit goes back to the holder, who may have paid it on purchase
+            Self::do_burn(&holder, asset_id)?;
 
-        //Insert asset to recipient
+            Self::deposit_event(Event::<T>::NftRevoked(sender, holder, asset_id));
 
-        if AssetsByOwner::<T>::contains_key(to) {
-            AssetsByOwner::<T>::try_mutate(&to, |asset_ids| -> DispatchResult {
-                // Check if the asset_id already in the owner
-                ensure!(
-                    asset_ids.iter().any(|i| asset_id == *i),
-                    Error::<T>::AssetIdAlreadyExist
-                );
-                asset_ids.push(asset_id);
-                Ok(())
-            })?;
-        } else {
-            let mut asset_ids = Vec::<AssetId>::new();
-            asset_ids.push(asset_id);
-            AssetsByOwner::<T>::insert(&to, asset_ids);
+            Ok(().into())
         }
 
-        Ok(())
-    }
+        #[pallet::weight(10_000)]
+        pub fn reissue_to(origin: OriginFor<T>, asset_id: AssetId, new_account: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
 
-    pub fn do_transfer(
-        sender: &T::AccountId,
-        to: &T::AccountId,
-        asset_id: AssetId) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
-        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            let (holder, issuer) = Self::get_bound_asset_issuer(&asset_id)?;
+            ensure!(
+                sender == issuer || T::AccountRecovery::is_recovered_by(&holder, &sender),
+                Error::<T>::NoPermission
+            );
+            Self::ensure_asset_unlocked(&asset_id)?;
+
+            Self::move_asset(&holder, &new_account, asset_id)?;
+
+            Self::deposit_event(Event::<T>::NftReissued(holder, new_account, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn redeem_voucher(
+            origin: OriginFor<T>,
+            voucher: MintVoucher<ClassIdOf<T>, BalanceOf<T>, T::BlockNumber>,
+            signature: T::OffchainSignature,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_id = voucher.class_id;
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(signature.verify(&Self::voucher_signing_payload(&voucher)[..], &class_info.owner), Error::<T>::InvalidVoucherSignature);
+            ensure!(<frame_system::Module<T>>::block_number() <= voucher.expiry, Error::<T>::VoucherExpired);
+            ensure!(!Self::is_voucher_used(class_id, voucher.nonce), Error::<T>::VoucherAlreadyUsed);
+
+            Self::increase_class_supply(class_id, 1)?;
+
+            //Buyer pays the price to the creator and the asset deposit to the class fund
+            let deposit = Self::mint_deposit_of(&class_info.data);
+            let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(class_id);
+            <T as Config>::Currency::transfer(&sender, &class_info.owner, voucher.price, ExistenceRequirement::KeepAlive)?;
+            <T as Config>::Currency::transfer(&sender, &class_fund, deposit, ExistenceRequirement::KeepAlive)?;
+            <T as Config>::Currency::reserve(&class_fund, deposit)?;
+
+            let new_nft_data = NftAssetData {
+                deposit,
+                name: voucher.name,
+                description: voucher.description,
+                properties: voucher.metadata.clone(),
+            };
+            let (asset_id, _token_id) = Self::do_mint(&sender, class_id, voucher.metadata, new_nft_data)?;
+            UsedVoucherNonces::<T>::insert(class_id, voucher.nonce, true);
+
+            Self::deposit_event(Event::<T>::VoucherRedeemed(sender, class_id, asset_id, voucher.nonce));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn sign_asset(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+
+            Supporters::<T>::try_mutate(asset_id, |supporters| -> DispatchResult {
+                ensure!(!supporters.contains(&sender), Error::<T>::AlreadySigned);
+                ensure!((supporters.len() as u32) < T::MaxSupporters::get(), Error::<T>::ExceededMaxSupporters);
+
+                supporters.push(sender.clone());
+                Ok(())
+            })?;
+
+            //Signing fee set by the asset owner goes to the asset owner
+            if let Some(fee) = Self::get_signing_fee(asset_id) {
+                <T as Config>::Currency::transfer(&sender, &asset_info.owner, fee, ExistenceRequirement::KeepAlive)?;
+            }
+
+            Self::deposit_event(Event::<T>::SignedNft(asset_id, sender));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_signing_fee(origin: OriginFor<T>, asset_id: AssetId, fee: Option<BalanceOf<T>>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+
+            match fee {
+                Some(fee) => SigningFees::<T>::insert(asset_id, fee),
+                None => SigningFees::<T>::remove(asset_id),
+            }
+
+            Self::deposit_event(Event::<T>::SigningFeeUpdated(sender, asset_id, fee));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn transfer_class(origin: OriginFor<T>, class_id: ClassIdOf<T>, to: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+                let info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+                ensure!(sender == info.owner, Error::<T>::NoPermission);
+
+                info.owner = to.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::TransferedNftClass(sender, to, class_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn destroy_class(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(class_info.total_issuance == Default::default(), Error::<T>::CannotDestroyClass);
+
+            NftModule::<T>::destroy_class(&sender, class_id)?;
+            let group_id = ClassDataCollection::<T>::take(class_id);
+            GroupClasses::<T>::remove(group_id, class_id);
+            FrozenMetadata::<T>::remove(MetadataTarget::Class(class_id));
+            ClassContracts::<T>::remove(class_id);
+            ClassAttributeSchemas::<T>::remove(class_id);
+            ClassSales::<T>::remove(class_id);
+            SalePurchases::<T>::remove_prefix(class_id);
+
+            //Release the class deposit from class fund back to the creator, who may have transferred the class
+            let depositor = ClassDepositors::<T>::take(class_id).unwrap_or(class_info.owner);
+            let deposit = class_info.data.deposit;
+            let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(class_id);
+            <T as Config>::Currency::unreserve(&class_fund, deposit);
+            <T as Config>::Currency::transfer(&class_fund, &depositor, deposit, ExistenceRequirement::AllowDeath)?;
+
+            Self::deposit_event(Event::<T>::DestroyedNftClass(sender, class_id, deposit));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn update_token_metadata(origin: OriginFor<T>, asset_id: AssetId, name: Vec<u8>, description: Vec<u8>, metadata: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let target = MetadataTarget::Token(asset_id);
+            ensure!(Self::can_update_metadata(&sender, &target)?, Error::<T>::NoPermission);
+            ensure!(!Self::is_metadata_frozen(target), Error::<T>::MetadataIsFrozen);
+
+            let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            orml_nft::Tokens::<T>::try_mutate(asset.0, asset.1, |token_info| -> DispatchResult {
+                let info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+                info.metadata = metadata.clone();
+                info.data.name = name;
+                info.data.description = description;
+                info.data.properties = metadata;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::TokenMetadataUpdated(sender, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn bundle(origin: OriginFor<T>, parent_id: AssetId, children: Vec<AssetId>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &parent_id)?, Error::<T>::NoPermission);
+            ensure!(!T::AssetsHandler::check_item_in_auction(parent_id), Error::<T>::AssetAlreadyInAuction);
+            Self::ensure_asset_unlocked(&parent_id)?;
+
+            let mut bundle = BundleChildren::<T>::get(parent_id).unwrap_or_default();
+            ensure!(!children.is_empty(), Error::<T>::InvalidBundle);
+            ensure!((bundle.len() + children.len()) as u32 <= T::MaxBundleSize::get(), Error::<T>::InvalidBundle);
+
+            let escrow = Self::bundle_account(parent_id);
+            for (i, child_id) in children.iter().enumerate() {
+                ensure!(*child_id != parent_id && !children[..i].contains(child_id), Error::<T>::InvalidBundle);
+                ensure!(Self::check_nft_ownership(&sender, child_id)?, Error::<T>::NoPermission);
+                ensure!(!T::AssetsHandler::check_item_in_auction(*child_id), Error::<T>::AssetAlreadyInAuction);
+                Self::ensure_asset_unlocked(child_id)?;
+                //Soulbound children would otherwise leave with the parent
+                ensure!(Self::get_class_data_of(child_id)?.token_type.is_transferable(), Error::<T>::NonTransferable);
+
+                Self::move_asset(&sender, &escrow, *child_id)?;
+                BundledIn::<T>::insert(child_id, parent_id);
+                bundle.push(*child_id);
+            }
+            BundleChildren::<T>::insert(parent_id, bundle);
+
+            Self::deposit_event(Event::<T>::NftBundled(sender, parent_id, children));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn unbundle(origin: OriginFor<T>, parent_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &parent_id)?, Error::<T>::NoPermission);
+            ensure!(!T::AssetsHandler::check_item_in_auction(parent_id), Error::<T>::AssetAlreadyInAuction);
+            Self::ensure_asset_unlocked(&parent_id)?;
+
+            let children = BundleChildren::<T>::take(parent_id).ok_or(Error::<T>::BundleNotFound)?;
+            let escrow = Self::bundle_account(parent_id);
+            for child_id in children {
+                BundledIn::<T>::remove(child_id);
+                Self::move_asset(&escrow, &sender, child_id)?;
+            }
+
+            Self::deposit_event(Event::<T>::NftUnbundled(sender, parent_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_sale(origin: OriginFor<T>, class_id: ClassIdOf<T>, sale: SaleConfig<T::AccountId, T::BlockNumber>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(sale.start <= sale.public_start && sale.public_start <= sale.end, Error::<T>::InvalidSaleConfig);
+            ensure!(sale.max_per_account > 0, Error::<T>::InvalidSaleConfig);
+
+            ClassSales::<T>::insert(class_id, sale);
+
+            Self::deposit_event(Event::<T>::SaleConfigured(class_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn cancel_sale(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(ClassSales::<T>::contains_key(class_id), Error::<T>::SaleNotFound);
+
+            ClassSales::<T>::remove(class_id);
+            SalePurchases::<T>::remove_prefix(class_id);
+
+            Self::deposit_event(Event::<T>::SaleCancelled(class_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(< T as Config >::WeightInfo::mint(* quantity))]
+        #[transactional]
+        pub fn purchase_mint(origin: OriginFor<T>, class_id: ClassIdOf<T>, quantity: u32, proof: Option<Vec<H256>>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+            let sale = ClassSales::<T>::get(class_id).ok_or(Error::<T>::SaleNotFound)?;
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+
+            let now = <frame_system::Module<T>>::block_number();
+            ensure!(sale.start <= now && now <= sale.end, Error::<T>::SaleNotActive);
+            if now < sale.public_start {
+                let allowed = match &sale.allowlist {
+                    Some(SaleAllowlist::Accounts(accounts)) => accounts.contains(&sender),
+                    Some(SaleAllowlist::MerkleRoot(root)) => {
+                        Self::verify_allowlist_proof(root, &sender, &proof.unwrap_or_default())
+                    }
+                    None => false,
+                };
+                ensure!(allowed, Error::<T>::NotInAllowlist);
+            }
+
+            SalePurchases::<T>::try_mutate(class_id, &sender, |purchased| -> DispatchResult {
+                let total = purchased.checked_add(quantity).ok_or(Error::<T>::ExceededPurchaseLimit)?;
+                ensure!(total <= sale.max_per_account, Error::<T>::ExceededPurchaseLimit);
+                *purchased = total;
+                Ok(())
+            })?;
+
+            //Stops once the supply cap is reached
+            Self::increase_class_supply(class_id, quantity)?;
+
+            //Proceeds go to the class owner, the buyer also pays the asset deposit
+            let total_price = sale.price.checked_mul(quantity.into()).ok_or(Error::<T>::InvalidQuantity)?;
+            T::FungibleTokenCurrency::transfer(sale.currency_id, &sender, &class_info.owner, total_price)?;
+
+            let deposit = Self::mint_deposit_of(&class_info.data);
+            let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(class_id);
+            let total_deposit = deposit.checked_mul(&quantity.into()).ok_or(Error::<T>::InvalidQuantity)?;
+            <T as Config>::Currency::transfer(&sender, &class_fund, total_deposit, ExistenceRequirement::KeepAlive)?;
+            <T as Config>::Currency::reserve(&class_fund, total_deposit)?;
+
+            let new_nft_data = NftAssetData {
+                deposit,
+                name: sale.name,
+                description: sale.description,
+                properties: sale.metadata.clone(),
+            };
+
+            let mut new_asset_ids: Vec<AssetId> = Vec::new();
+            for _ in 0..quantity {
+                let (asset_id, _token_id) = Self::do_mint(&sender, class_id, sale.metadata.clone(), new_nft_data.clone())?;
+                new_asset_ids.push(asset_id);
+            }
+
+            Self::deposit_event(Event::<T>::SalePurchased(sender, class_id, *new_asset_ids.first().unwrap(), *new_asset_ids.last().unwrap(), quantity));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_class_schema(origin: OriginFor<T>, class_id: ClassIdOf<T>, schema: Vec<(Vec<u8>, AttributeType)>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(class_info.total_issuance == Default::default(), Error::<T>::SchemaInUse);
+
+            ensure!(schema.len() as u32 <= T::MaxClassAttributes::get(), Error::<T>::InvalidAttributeSchema);
+            for (i, (key, attribute_type)) in schema.iter().enumerate() {
+                ensure!(!key.is_empty() && key.len() as u32 <= T::MaxAttributeKeyLength::get(), Error::<T>::InvalidAttributeSchema);
+                ensure!(!schema[..i].iter().any(|(k, _)| k == key), Error::<T>::InvalidAttributeSchema);
+                if let AttributeType::Enum(variants) = attribute_type {
+                    ensure!(!variants.is_empty(), Error::<T>::InvalidAttributeSchema);
+                    ensure!(
+                        variants.iter().all(|v| v.len() as u32 <= T::MaxAttributeValueLength::get()),
+                        Error::<T>::InvalidAttributeSchema
+                    );
+                }
+            }
+
+            ClassAttributeSchemas::<T>::insert(class_id, schema);
+
+            Self::deposit_event(Event::<T>::ClassSchemaSet(class_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_token_attributes(origin: OriginFor<T>, asset_id: AssetId, attributes: Vec<(Vec<u8>, AttributeValue)>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(!Self::is_metadata_frozen(MetadataTarget::Token(asset_id)), Error::<T>::MetadataIsFrozen);
+            Self::validate_attributes(asset.0, &attributes)?;
+
+            for (key, value) in attributes {
+                TokenAttributes::<T>::insert(asset_id, key, value);
+            }
+
+            Self::deposit_event(Event::<T>::TokenAttributesUpdated(sender, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn update_class_metadata(origin: OriginFor<T>, class_id: ClassIdOf<T>, metadata: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let target = MetadataTarget::Class(class_id);
+            ensure!(Self::can_update_metadata(&sender, &target)?, Error::<T>::NoPermission);
+            ensure!(!Self::is_metadata_frozen(target), Error::<T>::MetadataIsFrozen);
+
+            orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+                let info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+                info.metadata = metadata.clone();
+                info.data.metadata = metadata;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::<T>::ClassMetadataUpdated(sender, class_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn freeze_metadata(origin: OriginFor<T>, target: MetadataTarget<ClassIdOf<T>>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::can_update_metadata(&sender, &target)?, Error::<T>::NoPermission);
+            ensure!(!Self::is_metadata_frozen(target), Error::<T>::MetadataIsFrozen);
+
+            FrozenMetadata::<T>::insert(target, true);
+
+            Self::deposit_event(Event::<T>::MetadataFrozen(sender, target));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn equip(origin: OriginFor<T>, avatar_id: AssetId, slot: WearableSlot, wearable_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &avatar_id)?, Error::<T>::NoPermission);
+            ensure!(Self::check_nft_ownership(&sender, &wearable_id)?, Error::<T>::NoPermission);
+            ensure!(!T::AssetsHandler::check_item_in_auction(avatar_id), Error::<T>::AssetAlreadyInAuction);
+            ensure!(!T::AssetsHandler::check_item_in_auction(wearable_id), Error::<T>::AssetAlreadyInAuction);
+
+            let avatar_class = Self::get_class_data_of(&avatar_id)?;
+            ensure!(!avatar_class.collection_type.is_wearable(), Error::<T>::InvalidAvatar);
+            let wearable_class = Self::get_class_data_of(&wearable_id)?;
+            ensure!(wearable_class.collection_type.is_wearable(), Error::<T>::NotWearable);
+            //Equipped wearables follow the avatar on transfer
+            ensure!(wearable_class.token_type.is_transferable(), Error::<T>::NonTransferable);
+
+            Self::ensure_asset_unlocked(&wearable_id)?;
+            ensure!(!EquippedItems::<T>::contains_key(avatar_id, slot), Error::<T>::SlotAlreadyOccupied);
+
+            EquippedItems::<T>::insert(avatar_id, slot, wearable_id);
+            EquippedOn::<T>::insert(wearable_id, avatar_id);
+
+            Self::deposit_event(Event::<T>::WearableEquipped(sender, avatar_id, slot, wearable_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn unequip(origin: OriginFor<T>, avatar_id: AssetId, slot: WearableSlot) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &avatar_id)?, Error::<T>::NoPermission);
+
+            let wearable_id = EquippedItems::<T>::take(avatar_id, slot).ok_or(Error::<T>::SlotIsEmpty)?;
+            EquippedOn::<T>::remove(wearable_id);
+
+            Self::deposit_event(Event::<T>::WearableUnequipped(sender, avatar_id, slot, wearable_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn bind_contract(origin: OriginFor<T>, class_id: ClassIdOf<T>, contract: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+            ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+            ensure!(class_info.data.collection_type.is_executable(), Error::<T>::NotExecutable);
+
+            ClassContracts::<T>::insert(class_id, contract.clone());
+
+            Self::deposit_event(Event::<T>::ContractBound(class_id, contract));
+
+            Ok(().into())
+        }
+
+        /// Call the contract bound to the asset class as the asset owner, the SCALE encoded
+        /// asset id is appended to `input` so the contract knows which asset is executed
+        #[pallet::weight(10_000 + *gas_limit)]
+        #[transactional]
+        pub fn execute(origin: OriginFor<T>, asset_id: AssetId, input: Vec<u8>, gas_limit: Weight) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+
+            let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            let class_data = Self::get_class_data_of(&asset_id)?;
+            ensure!(class_data.collection_type.is_executable(), Error::<T>::NotExecutable);
+            let contract = ClassContracts::<T>::get(asset.0).ok_or(Error::<T>::ContractNotBound)?;
+
+            let mut input_data = input;
+            input_data.extend(asset_id.encode());
+
+            let (success, gas_consumed) = T::ContractExecutor::call_contract(sender.clone(), contract.clone(), input_data, gas_limit);
+            ensure!(success, Error::<T>::ContractExecutionFailed);
+
+            Self::deposit_event(Event::<T>::NftExecuted(sender, asset_id, contract));
+
+            Ok(Some(10_000 + gas_consumed).into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn approve(origin: OriginFor<T>, asset_id: AssetId, spender: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+
+            TokenApprovals::<T>::insert(asset_id, spender.clone());
+
+            Self::deposit_event(Event::<T>::Approval(sender, spender, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn cancel_approval(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+
+            TokenApprovals::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::<T>::ApprovalCancelled(sender, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn set_approval_for_all(origin: OriginFor<T>, operator: T::AccountId, approved: bool) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            if approved {
+                OperatorApprovals::<T>::insert(&sender, &operator, true);
+            } else {
+                OperatorApprovals::<T>::remove(&sender, &operator);
+            }
+
+            Self::deposit_event(Event::<T>::ApprovalForAll(sender, operator, approved));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn transfer_from(origin: OriginFor<T>, from: T::AccountId, to: T::AccountId, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!T::AssetsHandler::check_item_in_auction(asset_id), Error::<T>::AssetAlreadyInAuction);
+            ensure!(Self::check_nft_ownership(&from, &asset_id)?, Error::<T>::NoPermission);
+            ensure!(Self::is_approved_or_owner(&sender, &from, &asset_id), Error::<T>::NoPermission);
+
+            let token_id = Self::do_transfer(&from, &to, asset_id)?;
+
+            Self::deposit_event(Event::<T>::TransferedNft(from, to, token_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn fractionalize(origin: OriginFor<T>, asset_id: AssetId, total_shares: Balance) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!total_shares.is_zero(), Error::<T>::InvalidQuantity);
+            ensure!(!T::AssetsHandler::check_item_in_auction(asset_id), Error::<T>::AssetAlreadyInAuction);
+            ensure!(EquippedItems::<T>::iter_prefix(asset_id).next().is_none(), Error::<T>::AvatarHasEquippedItems);
+
+            //Lock asset in the vault
+            let vault = Self::fractional_vault();
+            Self::do_transfer(&sender, &vault, asset_id)?;
+
+            let currency_id = T::CurrencyIdAllocator::allocate_currency_id()?;
+            T::FungibleTokenCurrency::deposit(currency_id, &sender, total_shares)?;
+
+            FractionalAssets::<T>::insert(asset_id, FractionalAssetInfo {
+                currency_id,
+                total_shares,
+            });
+
+            Self::deposit_event(Event::<T>::NftFractionalized(sender, asset_id, currency_id, total_shares));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn redeem_fractions(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let fractional_info = FractionalAssets::<T>::take(asset_id).ok_or(Error::<T>::AssetNotFractionalized)?;
+            ensure!(
+                T::FungibleTokenCurrency::free_balance(fractional_info.currency_id, &sender) >= fractional_info.total_shares,
+                Error::<T>::InsufficientShares
+            );
+
+            T::FungibleTokenCurrency::withdraw(fractional_info.currency_id, &sender, fractional_info.total_shares)?;
+
+            //Release asset from the vault
+            let vault = Self::fractional_vault();
+            Self::do_transfer(&vault, &sender, asset_id)?;
+
+            Self::deposit_event(Event::<T>::NftRedeemed(sender, asset_id, fractional_info.currency_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn lend(
+            origin: OriginFor<T>,
+            asset_id: AssetId,
+            borrower: T::AccountId,
+            duration: T::BlockNumber,
+            price: Option<(CurrencyId, Balance)>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!duration.is_zero(), Error::<T>::InvalidRentalDuration);
+            ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+            ensure!(!T::AssetsHandler::check_item_in_auction(asset_id), Error::<T>::AssetAlreadyInAuction);
+            Self::ensure_asset_unlocked(&asset_id)?;
+
+            RentalOffers::<T>::insert(asset_id, RentalOffer {
+                lender: sender.clone(),
+                borrower: borrower.clone(),
+                duration,
+                price,
+            });
+
+            Self::deposit_event(Event::<T>::RentalOffered(sender, borrower, asset_id, duration));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn cancel_rental_offer(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let offer = RentalOffers::<T>::get(asset_id).ok_or(Error::<T>::RentalOfferNotFound)?;
+            ensure!(offer.lender == sender, Error::<T>::NoPermission);
+
+            RentalOffers::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::<T>::RentalOfferCancelled(sender, asset_id));
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(10_000)]
+        #[transactional]
+        pub fn accept_rental(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let offer = RentalOffers::<T>::get(asset_id).ok_or(Error::<T>::RentalOfferNotFound)?;
+            ensure!(offer.borrower == sender, Error::<T>::NoPermission);
+            ensure!(Self::check_nft_ownership(&offer.lender, &asset_id)?, Error::<T>::NoPermission);
+            ensure!(!T::AssetsHandler::check_item_in_auction(asset_id), Error::<T>::AssetAlreadyInAuction);
+            Self::ensure_asset_unlocked(&asset_id)?;
+
+            let expires_at = <frame_system::Module<T>>::block_number()
+                .checked_add(&offer.duration)
+                .ok_or(Error::<T>::RentalDurationOverflow)?;
+
+            //Rental fee goes straight to the owner, in native currency or country token
+            if let Some((currency_id, amount)) = offer.price {
+                T::FungibleTokenCurrency::transfer(currency_id, &sender, &offer.lender, amount)?;
+            }
+
+            AssetUsers::<T>::insert(asset_id, NftRentalInfo {
+                user: sender.clone(),
+                expires_at,
+            });
+            RentalExpiry::<T>::insert(expires_at, asset_id, ());
+            RentalOffers::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::<T>::NftRented(offer.lender, sender, asset_id, expires_at));
+
+            Ok(().into())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut expired: u64 = 0;
+            for (asset_id, _) in RentalExpiry::<T>::drain_prefix(&now) {
+                if let Some(rental) = AssetUsers::<T>::take(asset_id) {
+                    Self::deposit_event(Event::<T>::RentalExpired(rental.user, asset_id));
+                }
+                expired += 1;
+            }
+            T::DbWeight::get().reads_writes(expired, expired.saturating_mul(2))
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Self::storage_version() == Releases::V1_0_0 {
+                let weight = Self::migrate_class_data_to_v2();
+                StorageVersion::<T>::put(Releases::V2_0_0);
+                weight.saturating_add(T::DbWeight::get().writes(1))
+            } else {
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Bytes the class owner signs for a voucher, bound to this chain by its genesis hash
+    pub fn voucher_signing_payload(voucher: &MintVoucher<ClassIdOf<T>, BalanceOf<T>, T::BlockNumber>) -> Vec<u8> {
+        let genesis_hash = <frame_system::Module<T>>::block_hash(T::BlockNumber::zero());
+        (VOUCHER_SIGNING_DOMAIN, genesis_hash, voucher).encode()
+    }
+
+    /// Translate classes created before supply caps, royalties and mint deposits to the current layout and index them by group
+    fn migrate_class_data_to_v2() -> Weight {
+        let mut translated: u64 = 0;
+        orml_nft::Classes::<T>::translate::<orml_nft::ClassInfo<TokenIdOf<T>, T::AccountId, OldNftClassData<BalanceOf<T>>>, _>(
+            |class_id, old| {
+                translated += 1;
+                //Creator is unknown for old classes, the current owner gets the deposit back
+                ClassDepositors::<T>::insert(class_id, old.owner.clone());
+                Some(orml_nft::ClassInfo {
+                    metadata: old.metadata,
+                    total_issuance: old.total_issuance,
+                    owner: old.owner.clone(),
+                    data: NftClassData {
+                        deposit: old.data.deposit,
+                        //Tokens used to pay the pallet wide asset deposit
+                        mint_deposit: T::CreateAssetDeposit::get(),
+                        metadata: old.data.metadata,
+                        token_type: old.data.token_type,
+                        collection_type: old.data.collection_type,
+                        //Old classes never tracked their supply
+                        total_supply: old.total_issuance.saturated_into::<u64>(),
+                        initial_supply: None,
+                        royalty_fee: Perbill::zero(),
+                        royalty_beneficiary: old.owner,
+                    },
+                })
+            },
+        );
+
+        //Classes created before groups tracked their classes
+        let mut indexed: u64 = 0;
+        for (class_id, group_id) in ClassDataCollection::<T>::iter() {
+            GroupClasses::<T>::insert(group_id, class_id, ());
+            indexed += 1;
+        }
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(indexed), translated.saturating_add(indexed))
+    }
+
+    /// Signed account, or None for root
+    fn ensure_signed_or_root(origin: OriginFor<T>) -> Result<Option<T::AccountId>, DispatchError> {
+        let raw_origin: Result<frame_system::RawOrigin<T::AccountId>, OriginFor<T>> = origin.into();
+        match raw_origin {
+            Ok(frame_system::RawOrigin::Root) => Ok(None),
+            Ok(frame_system::RawOrigin::Signed(who)) => Ok(Some(who)),
+            _ => Err(DispatchError::BadOrigin),
+        }
+    }
+
+    /// Root can manage any group, signed accounts only the groups they created
+    fn ensure_group_owner(sender: Option<T::AccountId>, group_id: GroupCollectionId) -> DispatchResult {
+        ensure!(GroupCollections::<T>::contains_key(group_id), Error::<T>::CollectionIsNotExist);
+        if let Some(sender) = sender {
+            let (owner, _) = GroupCollectionOwners::<T>::get(group_id).ok_or(Error::<T>::NoPermission)?;
+            ensure!(sender == owner, Error::<T>::NoPermission);
+        }
+
+        Ok(())
+    }
+
+    /// Deposit charged to create a class
+    pub fn class_deposit_minimum() -> BalanceOf<T> {
+        ClassDepositMinimum::<T>::get().unwrap_or_else(T::CreateClassDeposit::get)
+    }
+
+    /// Lowest deposit a class can charge per token
+    pub fn asset_deposit_minimum() -> BalanceOf<T> {
+        AssetDepositMinimum::<T>::get().unwrap_or_else(T::CreateAssetDeposit::get)
+    }
+
+    /// Deposit charged per token of a class, never below the current pallet minimum
+    pub fn mint_deposit_of(class_data: &NftClassData<BalanceOf<T>, T::AccountId>) -> BalanceOf<T> {
+        class_data.mint_deposit.max(Self::asset_deposit_minimum())
+    }
+
+    /// Check every attribute is declared in the class schema with a matching value
+    fn validate_attributes(class_id: ClassIdOf<T>, attributes: &[(Vec<u8>, AttributeValue)]) -> DispatchResult {
+        if attributes.is_empty() {
+            return Ok(());
+        }
+
+        let schema = ClassAttributeSchemas::<T>::get(class_id);
+        for (key, value) in attributes.iter() {
+            let (_, attribute_type) = schema.iter().find(|(k, _)| k == key).ok_or(Error::<T>::UnknownAttribute)?;
+            ensure!(value.matches(attribute_type), Error::<T>::AttributeTypeMismatch);
+            if let AttributeValue::String(text) = value {
+                ensure!(text.len() as u32 <= T::MaxAttributeValueLength::get(), Error::<T>::AttributeValueTooLong);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the buyer is a leaf of the allowlist merkle tree
+    fn verify_allowlist_proof(root: &H256, who: &T::AccountId, proof: &[H256]) -> bool {
+        let mut computed = H256::from(blake2_256(&who.encode()));
+        for node in proof.iter() {
+            computed = if computed <= *node {
+                H256::from(blake2_256(&(computed, *node).encode()))
+            } else {
+                H256::from(blake2_256(&(*node, computed).encode()))
+            };
+        }
+
+        computed == *root
+    }
+
+    /// Classes belonging to a group
+    pub fn group_classes(group_id: GroupCollectionId) -> Vec<ClassIdOf<T>> {
+        GroupClasses::<T>::iter_prefix(group_id).map(|(class_id, _)| class_id).collect()
+    }
+
+    fn do_create_group_collection(
+        name: Vec<u8>,
+        properties: Vec<u8>,
+    ) -> Result<GroupCollectionId, DispatchError> {
+        let next_group_collection_id = NextGroupCollectionId::<T>::try_mutate(
+            |collection_id| -> Result<GroupCollectionId, DispatchError> {
+                let current_id = *collection_id;
+
+                *collection_id = collection_id
+                    .checked_add(One::one())
+                    .ok_or(Error::<T>::NoAvailableCollectionId)?;
+
+                Ok(current_id)
+            },
+        )?;
+
+        let collection_data = NftGroupCollectionData {
+            name,
+            properties,
+        };
+
+        <GroupCollections<T>>::insert(next_group_collection_id, collection_data);
+
+        Ok(next_group_collection_id)
+    }
+
+    fn handle_asset_ownership_transfer(
+        sender: &T::AccountId,
+        to: &T::AccountId,
+        asset_id: AssetId,
+    ) -> DispatchResult {
+        //Remove asset from sender
+        AssetsByOwner::<T>::try_mutate(&sender, |asset_ids| -> DispatchResult {
+            // Check if the asset_id already in the owner
+            let asset_index = asset_ids.iter().position(|x| *x == asset_id).unwrap();
+            asset_ids.remove(asset_index);
+
+            Ok(())
+        })?;
+
+        //Insert asset to recipient
+
+        if AssetsByOwner::<T>::contains_key(to) {
+            AssetsByOwner::<T>::try_mutate(&to, |asset_ids| -> DispatchResult {
+                // Check if the asset_id already in the owner
+                ensure!(
+                    !asset_ids.iter().any(|i| asset_id == *i),
+                    Error::<T>::AssetIdAlreadyExist
+                );
+                asset_ids.push(asset_id);
+                Ok(())
+            })?;
+        } else {
+            let mut asset_ids = Vec::<AssetId>::new();
+            asset_ids.push(asset_id);
+            AssetsByOwner::<T>::insert(&to, asset_ids);
+        }
+
+        Ok(())
+    }
+
+    /// Mint a single token to owner and register its asset id
+    fn do_mint(
+        owner: &T::AccountId,
+        class_id: ClassIdOf<T>,
+        metadata: Vec<u8>,
+        data: NftAssetData<BalanceOf<T>>,
+    ) -> Result<(AssetId, TokenIdOf<T>), DispatchError> {
+        let asset_id = NextAssetId::<T>::try_mutate(|id| -> Result<AssetId, DispatchError> {
+            let current_id = *id;
+            *id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAssetId)?;
+
+            Ok(current_id)
+        })?;
+
+        if AssetsByOwner::<T>::contains_key(&owner) {
+            AssetsByOwner::<T>::try_mutate(
+                &owner,
+                |asset_ids| -> DispatchResult {
+                    // Check if the asset_id already in the owner
+                    ensure!(!asset_ids.iter().any(|i| asset_id == *i), Error::<T>::AssetIdAlreadyExist);
+                    asset_ids.push(asset_id);
+                    Ok(())
+                },
+            )?;
+        } else {
+            let mut assets = Vec::<AssetId>::new();
+            assets.push(asset_id);
+            AssetsByOwner::<T>::insert(&owner, assets)
+        }
+
+        let token_id = NftModule::<T>::mint(&owner, class_id, metadata, data)?;
+        Assets::<T>::insert(asset_id, (class_id, token_id));
+
+        Ok((asset_id, token_id))
+    }
+
+    fn increase_class_supply(
+        class_id: ClassIdOf<T>,
+        quantity: u32,
+    ) -> DispatchResult {
+        orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+            let info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+            let new_supply = info.data.total_supply
+                .checked_add(quantity as u64)
+                .ok_or(Error::<T>::ExceededMaxSupply)?;
+
+            if let Some(max_supply) = info.data.initial_supply {
+                ensure!(new_supply <= max_supply, Error::<T>::ExceededMaxSupply);
+            }
+
+            info.data.total_supply = new_supply;
+            Ok(())
+        })
+    }
+
+    fn remove_asset_from_owner(
+        owner: &T::AccountId,
+        asset_id: AssetId,
+    ) -> DispatchResult {
+        AssetsByOwner::<T>::try_mutate_exists(&owner, |asset_ids| -> DispatchResult {
+            let ids = asset_ids.as_mut().ok_or(Error::<T>::AssetIdNotFound)?;
+            let asset_index = ids.iter().position(|x| *x == asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            ids.remove(asset_index);
+
+            if ids.is_empty() {
+                *asset_ids = None;
+            }
+
+            Ok(())
+        })
+    }
+
+    pub fn do_transfer(
+        sender: &T::AccountId,
+        to: &T::AccountId,
+        asset_id: AssetId) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
+        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
 
         let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
         let data = class_info.data;
@@ -494,16 +1974,199 @@ impl<T: Config> Module<T> {
             TokenType::Transferable => {
                 let check_ownership = Self::check_nft_ownership(&sender, &asset_id)?;
                 ensure!(check_ownership, Error::<T>::NoPermission);
+                Self::ensure_asset_unlocked(&asset_id)?;
 
-                Self::handle_asset_ownership_transfer(&sender, &to, asset_id);
+                Self::move_asset(&sender, &to, asset_id)?;
 
-                NftModule::<T>::transfer(&sender, &to, asset.clone())?;
                 Ok(asset.1)
             }
             TokenType::BoundToAddress => Err(Error::<T>::NonTransferable.into())
         }
     }
 
+    /// Move asset and any equipped wearables to a new owner, without transferability checks
+    fn move_asset(
+        from: &T::AccountId,
+        to: &T::AccountId,
+        asset_id: AssetId) -> DispatchResult {
+        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+        //Soulbound wearables equipped before equip checked transferability must be unequipped first
+        for (_slot, wearable_id) in EquippedItems::<T>::iter_prefix(asset_id) {
+            ensure!(Self::get_class_data_of(&wearable_id)?.token_type.is_transferable(), Error::<T>::NonTransferable);
+        }
+
+        Self::handle_asset_ownership_transfer(&from, &to, asset_id)?;
+
+        NftModule::<T>::transfer(&from, &to, asset)?;
+        TokenApprovals::<T>::remove(asset_id);
+        RentalOffers::<T>::remove(asset_id);
+        SigningFees::<T>::remove(asset_id);
+
+        //Equipped wearables follow the avatar
+        for (_slot, wearable_id) in EquippedItems::<T>::iter_prefix(asset_id) {
+            let wearable = Assets::<T>::get(wearable_id).ok_or(Error::<T>::AssetIdNotFound)?;
+            Self::handle_asset_ownership_transfer(&from, &to, wearable_id)?;
+            NftModule::<T>::transfer(&from, &to, wearable)?;
+            TokenApprovals::<T>::remove(wearable_id);
+        }
+
+        Ok(())
+    }
+
+    /// Burn asset held by owner and release its deposit from class fund to refund_to
+    fn do_burn(
+        owner: &T::AccountId,
+        asset_id: AssetId) -> Result<BalanceOf<T>, DispatchError> {
+        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+        let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+        Self::ensure_asset_unlocked(&asset_id)?;
+        ensure!(EquippedItems::<T>::iter_prefix(asset_id).next().is_none(), Error::<T>::AvatarHasEquippedItems);
+        ensure!(!BundleChildren::<T>::contains_key(asset_id), Error::<T>::AssetHasBundle);
+
+        NftModule::<T>::burn(&owner, asset)?;
+        Assets::<T>::remove(asset_id);
+        FrozenMetadata::<T>::remove(MetadataTarget::Token(asset_id));
+        TokenApprovals::<T>::remove(asset_id);
+        RentalOffers::<T>::remove(asset_id);
+        Supporters::<T>::remove(asset_id);
+        SigningFees::<T>::remove(asset_id);
+        TokenAttributes::<T>::remove_prefix(asset_id);
+        Self::remove_asset_from_owner(&owner, asset_id)?;
+
+        let deposit = asset_info.data.deposit;
+        let class_fund: T::AccountId = T::ModuleId::get().into_sub_account(asset.0);
+        <T as Config>::Currency::unreserve(&class_fund, deposit);
+        <T as Config>::Currency::transfer(&class_fund, &owner, deposit, ExistenceRequirement::AllowDeath)?;
+
+        Ok(deposit)
+    }
+
+    /// Soulbound asset and its class owner, rejects transferable assets
+    fn get_bound_asset_issuer(asset_id: &AssetId) -> Result<(T::AccountId, T::AccountId), DispatchError> {
+        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+        let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+        ensure!(class_info.data.token_type == TokenType::BoundToAddress, Error::<T>::NotBoundToAddress);
+        let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+
+        Ok((asset_info.owner, class_info.owner))
+    }
+
+    /// Token metadata can be changed by the token owner or the class owner, class metadata by the class owner only
+    fn can_update_metadata(
+        sender: &T::AccountId,
+        target: &MetadataTarget<ClassIdOf<T>>) -> Result<bool, DispatchError> {
+        match target {
+            MetadataTarget::Class(class_id) => {
+                let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+                Ok(sender == &class_info.owner)
+            }
+            MetadataTarget::Token(asset_id) => {
+                let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+                let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+                let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+                Ok(sender == &asset_info.owner || sender == &class_info.owner)
+            }
+        }
+    }
+
+    pub fn get_royalty_info(asset_id: &AssetId) -> Option<(T::AccountId, Perbill)> {
+        let asset = Assets::<T>::get(asset_id)?;
+        let class_info = NftModule::<T>::classes(asset.0)?;
+
+        Some((class_info.data.royalty_beneficiary, class_info.data.royalty_fee))
+    }
+
+    fn get_class_data_of(asset_id: &AssetId) -> Result<NftClassData<BalanceOf<T>, T::AccountId>, DispatchError> {
+        let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+        let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+
+        Ok(class_info.data)
+    }
+
+    /// Account escrowing the children of a bundle, follows the parent asset
+    pub fn bundle_account(parent_id: AssetId) -> T::AccountId {
+        T::EscrowModuleId::get().into_sub_account((b"bndl", parent_id))
+    }
+
+    /// Account holding fractionalized assets
+    pub fn fractional_vault() -> T::AccountId {
+        T::EscrowModuleId::get().into_sub_account(b"frac")
+    }
+
+    /// Check the asset is not held by any feature that prevents it from being transferred or auctioned
+    pub fn ensure_asset_unlocked(asset_id: &AssetId) -> DispatchResult {
+        ensure!(!EquippedOn::<T>::contains_key(asset_id), Error::<T>::AssetIsEquipped);
+        ensure!(!FractionalAssets::<T>::contains_key(asset_id), Error::<T>::AssetIsFractionalized);
+        ensure!(!AssetUsers::<T>::contains_key(asset_id), Error::<T>::AssetIsRented);
+        ensure!(!BundledIn::<T>::contains_key(asset_id), Error::<T>::AssetIsBundled);
+        if !T::TransferEquippedWithAvatar::get() {
+            ensure!(EquippedItems::<T>::iter_prefix(asset_id).next().is_none(), Error::<T>::AvatarHasEquippedItems);
+        }
+
+        Ok(())
+    }
+
+    /// Page of assets owned by `owner`, skipping the first `start` assets and returning at most
+    /// MAX_ASSETS_PAGE_SIZE. The owner's whole asset list is still read and decoded for every page,
+    /// so the cost grows with the number of assets held, not with the page size
+    pub fn assets_by_owner(owner: &T::AccountId, start: u32, limit: u32) -> Vec<AssetId> {
+        Self::get_assets_by_owner(owner)
+            .into_iter()
+            .skip(start as usize)
+            .take(limit.min(MAX_ASSETS_PAGE_SIZE) as usize)
+            .collect()
+    }
+
+    pub fn asset_details(asset_id: AssetId) -> Option<NftAssetDetails<T::AccountId, ClassIdOf<T>, TokenIdOf<T>, BalanceOf<T>>> {
+        let asset = Assets::<T>::get(asset_id)?;
+        let token_info = NftModule::<T>::tokens(asset.0, asset.1)?;
+
+        Some(NftAssetDetails {
+            owner: token_info.owner,
+            class_id: asset.0,
+            token_id: asset.1,
+            metadata: token_info.metadata,
+            data: token_info.data,
+            in_auction: T::AssetsHandler::check_item_in_auction(asset_id),
+        })
+    }
+
+    pub fn class_details(class_id: ClassIdOf<T>) -> Option<NftClassDetails<T::AccountId, TokenIdOf<T>, BalanceOf<T>>> {
+        let class_info = NftModule::<T>::classes(class_id)?;
+
+        Some(NftClassDetails {
+            owner: class_info.owner,
+            metadata: class_info.metadata,
+            total_issuance: class_info.total_issuance,
+            data: class_info.data,
+            group_collection_id: Self::class_group_collection_id(class_id),
+        })
+    }
+
+    pub fn class_group_collection(class_id: ClassIdOf<T>) -> Option<(GroupCollectionId, NftGroupCollectionData)> {
+        let collection_id = Self::class_group_collection_id(class_id)?;
+        let collection_data = Self::get_group_collection(collection_id)?;
+
+        Some((collection_id, collection_data))
+    }
+
+    fn class_group_collection_id(class_id: ClassIdOf<T>) -> Option<GroupCollectionId> {
+        if ClassDataCollection::<T>::contains_key(class_id) {
+            Some(Self::get_class_collection(class_id))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_approved_or_owner(
+        spender: &T::AccountId,
+        owner: &T::AccountId,
+        asset_id: &AssetId) -> bool {
+        spender == owner
+            || Self::get_approved(asset_id).as_ref() == Some(spender)
+            || Self::is_approved_for_all(owner, spender)
+    }
+
     pub fn check_nft_ownership(
         sender: &T::AccountId,
         asset_id: &AssetId) -> Result<bool, DispatchError> {
@@ -536,4 +2199,46 @@ for Module<T>
     ) -> bool {
         return T::AuctionHandler::check_item_in_auction(asset_id);
     }
-}
\ No newline at end of file
+}
+
+impl<T: Config> NftAttributes for Module<T> {
+    fn attribute(asset_id: AssetId, key: &[u8]) -> Option<AttributeValue> {
+        TokenAttributes::<T>::get(asset_id, key)
+    }
+
+    fn attributes(asset_id: AssetId) -> Vec<(Vec<u8>, AttributeValue)> {
+        TokenAttributes::<T>::iter_prefix(asset_id).collect()
+    }
+}
+
+pub trait ContractExecutor<AccountId> {
+    //Call contract as caller, returns whether the call succeeded and the gas consumed
+    fn call_contract(
+        caller: AccountId,
+        contract: AccountId,
+        input_data: Vec<u8>,
+        gas_limit: Weight,
+    ) -> (bool, Weight);
+}
+
+impl<AccountId> ContractExecutor<AccountId> for () {
+    fn call_contract(
+        _caller: AccountId,
+        _contract: AccountId,
+        _input_data: Vec<u8>,
+        _gas_limit: Weight,
+    ) -> (bool, Weight) {
+        (false, 0)
+    }
+}
+
+pub trait AccountRecovery<AccountId> {
+    //Whether rescuer has recovered the lost account
+    fn is_recovered_by(lost: &AccountId, rescuer: &AccountId) -> bool;
+}
+
+impl<AccountId> AccountRecovery<AccountId> for () {
+    fn is_recovered_by(_lost: &AccountId, _rescuer: &AccountId) -> bool {
+        false
+    }
+}
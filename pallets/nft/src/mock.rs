@@ -4,12 +4,13 @@ use super::*;
 
 use crate as nft;
 use frame_support::{
-    construct_runtime, impl_outer_event, impl_outer_origin, impl_outer_dispatch, parameter_types, traits::EnsureOrigin, weights::Weight,
+    construct_runtime, impl_outer_event, impl_outer_origin, impl_outer_dispatch, parameter_types, traits::{EnsureOrigin, GenesisBuild}, weights::Weight,
 };
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
+use std::cell::RefCell;
 use sp_runtime::testing::Header;
-use sp_runtime::traits::IdentityLookup;
+use sp_runtime::traits::{IdentityLookup, Lazy};
 use orml_currencies::BasicCurrencyAdapter;
 use primitives::{CurrencyId, Amount, ItemId};
 use auction_manager::{AuctionHandler, AuctionType, OnNewBidResult, Change, AuctionInfo, Auction};
@@ -24,6 +25,8 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const RESCUER: AccountId = 3;
+pub const CONTRACT: AccountId = 100;
 pub const CLASS_ID: <Runtime as orml_nft::Config>::ClassId = 0;
 pub const CLASS_ID_NOT_EXIST: <Runtime as orml_nft::Config>::ClassId = 1;
 pub const TOKEN_ID: <Runtime as orml_nft::Config>::TokenId = 0;
@@ -79,6 +82,16 @@ parameter_types! {
     pub const GetNativeCurrencyId: CurrencyId = 0;
 }
 
+impl orml_tokens::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+}
+
 pub struct MockAuctionManager;
 
 impl Auction<AccountId, BlockNumber> for MockAuctionManager {
@@ -96,7 +109,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
         todo!()
     }
 
-    fn create_auction(auction_type: AuctionType, item_id: ItemId, end: Option<u64>, recipient: u128, initial_amount: Self::Balance, start: u64) -> Result<u64, DispatchError> {
+    fn create_auction(auction_type: AuctionType<Self::Balance>, item_id: ItemId, end: Option<u64>, recipient: u128, initial_amount: Self::Balance, start: u64) -> Result<u64, DispatchError> {
         todo!()
     }
 
@@ -116,15 +129,43 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 parameter_types! {
     pub CreateClassDeposit: Balance = 2;
     pub CreateAssetDeposit: Balance = 1;
+    pub CreateGroupDeposit: Balance = 5;
+    pub MaxMintDeposit: Balance = 1000;
+    pub MaxRoyaltyFee: Perbill = Perbill::from_percent(25);
+    pub TransferEquippedWithAvatar: bool = true;
+    pub MaxSupporters: u32 = 2;
+    pub MaxBatchMint: u32 = 3;
+    pub MaxBundleSize: u32 = 2;
+    pub MaxClassAttributes: u32 = 3;
+    pub MaxAttributeKeyLength: u32 = 8;
+    pub MaxAttributeValueLength: u32 = 8;
     pub NftModuleId: ModuleId = ModuleId(*b"bit/bNFT");
+    pub NftEscrowModuleId: ModuleId = ModuleId(*b"bit/nfte");
 }
 
 impl Config for Runtime {
     type Event = Event;
     type CreateClassDeposit = CreateClassDeposit;
     type CreateAssetDeposit = CreateAssetDeposit;
+    type CreateGroupDeposit = CreateGroupDeposit;
+    type MaxMintDeposit = MaxMintDeposit;
+    type MaxRoyaltyFee = MaxRoyaltyFee;
+    type TransferEquippedWithAvatar = TransferEquippedWithAvatar;
+    type MaxSupporters = MaxSupporters;
+    type MaxBatchMint = MaxBatchMint;
+    type MaxBundleSize = MaxBundleSize;
+    type MaxClassAttributes = MaxClassAttributes;
+    type MaxAttributeKeyLength = MaxAttributeKeyLength;
+    type MaxAttributeValueLength = MaxAttributeValueLength;
+    type ContractExecutor = MockContractExecutor;
+    type FungibleTokenCurrency = Tokens;
+    type CurrencyIdAllocator = MockCurrencyIdAllocator;
+    type AccountRecovery = MockAccountRecovery;
+    type OffchainSignature = MockSignature;
+    type OffchainPublic = MockSigner;
     type Currency = Balances;
     type ModuleId = NftModuleId;
+    type EscrowModuleId = NftEscrowModuleId;
     type AuctionHandler = MockAuctionManager;
     type WeightInfo = ();
     type AssetsHandler = Handler;
@@ -133,7 +174,7 @@ impl Config for Runtime {
 impl orml_nft::Config for Runtime {
     type ClassId = u32;
     type TokenId = u64;
-    type ClassData = nft::NftClassData<Balance>;
+    type ClassData = nft::NftClassData<Balance, AccountId>;
     type TokenData = nft::NftAssetData<Balance>;
 }
 
@@ -149,9 +190,10 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Module, Call, Config, Storage, Event<T>},
-		Nft: nft::{Module, Call, Event<T>},
+		Nft: nft::{Module, Call, Storage, Config, Event<T>},
 		OrmlNft: orml_nft::{Module, Storage, Config<T>},
 		Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+		Tokens: orml_tokens::{Module, Storage, Event<T>, Config<T>},
 	}
 );
 
@@ -176,12 +218,24 @@ impl ExtBuilder {
             .assimilate_storage(&mut t)
             .unwrap();
 
+        GenesisBuild::<Runtime>::assimilate_storage(&nft::GenesisConfig::default(), &mut t).unwrap();
+
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
         ext
     }
 }
 
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        Nft::on_finalize(System::block_number());
+        System::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        System::on_initialize(System::block_number());
+        Nft::on_initialize(System::block_number());
+    }
+}
+
 pub fn last_event() -> Event {
     frame_system::Module::<Runtime>::events()
         .pop()
@@ -197,4 +251,62 @@ impl AssetHandler for Handler {
     ) -> bool {
         return MockAuctionManager::check_item_in_auction(asset_id);
     }
-}
\ No newline at end of file
+}
+
+pub struct MockContractExecutor;
+
+impl ContractExecutor<AccountId> for MockContractExecutor {
+    fn call_contract(
+        _caller: AccountId,
+        contract: AccountId,
+        _input_data: Vec<u8>,
+        gas_limit: Weight,
+    ) -> (bool, Weight) {
+        (contract == CONTRACT, gas_limit / 2)
+    }
+}
+
+thread_local! {
+    static NEXT_CURRENCY_ID: RefCell<CurrencyId> = RefCell::new(1);
+}
+
+pub struct MockCurrencyIdAllocator;
+
+impl CurrencyIdAllocator for MockCurrencyIdAllocator {
+    fn allocate_currency_id() -> Result<CurrencyId, DispatchError> {
+        NEXT_CURRENCY_ID.with(|v| {
+            let currency_id = *v.borrow();
+            *v.borrow_mut() = currency_id + 1;
+            Ok(currency_id)
+        })
+    }
+}
+
+pub struct MockAccountRecovery;
+
+impl AccountRecovery<AccountId> for MockAccountRecovery {
+    fn is_recovered_by(lost: &AccountId, rescuer: &AccountId) -> bool {
+        *lost == BOB && *rescuer == RESCUER
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+pub struct MockSigner(pub AccountId);
+
+impl IdentifyAccount for MockSigner {
+    type AccountId = AccountId;
+
+    fn into_account(self) -> AccountId {
+        self.0
+    }
+}
+
+impl Verify for MockSignature {
+    type Signer = MockSigner;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+        self.0 == *signer && msg.get() == &self.1[..]
+    }
+}
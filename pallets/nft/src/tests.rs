@@ -34,6 +34,10 @@ fn init_test_nft(owner: Origin) {
         COLLECTION_ID,
         TokenType::Transferable,
         CollectionType::Collectable,
+        None,
+        Perbill::zero(),
+        None,
+        None,
     ));
     assert_ok!(Nft::mint(
         owner.clone(),
@@ -41,7 +45,8 @@ fn init_test_nft(owner: Origin) {
         vec![1],
         vec![1],
         vec![1],
-        1
+        1,
+        vec![]
     ));
 }
 
@@ -94,19 +99,26 @@ fn create_class_should_work() {
             COLLECTION_ID,
             TokenType::Transferable,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
 
         let class_data = NftClassData
         {
             deposit: 2,
+            mint_deposit: 1,
             metadata: vec![1],
             token_type: TokenType::Transferable,
             collection_type: CollectionType::Collectable,
             total_supply: Default::default(),
             initial_supply: Default::default(),
+            royalty_fee: Perbill::zero(),
+            royalty_beneficiary: ALICE,
         };
 
-        let class_info = orml_nft::ClassInfo::<u64, AccountId, NftClassData<u128>> {
+        let class_info = orml_nft::ClassInfo::<u64, AccountId, NftClassData<u128, AccountId>> {
             metadata: vec![1],
             total_issuance: Default::default(),
             owner: ALICE,
@@ -152,7 +164,8 @@ fn mint_asset_should_work() {
             vec![1],
             vec![1],
             vec![1],
-            2
+            2,
+            vec![]
         ));
 
         assert_eq!(Nft::next_asset_id(), 3);
@@ -178,6 +191,10 @@ fn mint_asset_should_fail() {
             COLLECTION_ID,
             TokenType::Transferable,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
         assert_noop!(Nft::mint(
             origin.clone(),
@@ -185,7 +202,8 @@ fn mint_asset_should_fail() {
             vec![1],
             vec![1],
             vec![1],
-            0
+            0,
+            vec![]
         ), Error::<Runtime>::InvalidQuantity);
         assert_noop!(Nft::mint(
             origin.clone(),
@@ -193,7 +211,8 @@ fn mint_asset_should_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ), Error::<Runtime>::ClassIdNotFound);
         assert_noop!(Nft::mint(
             invalid_owner.clone(),
@@ -201,7 +220,8 @@ fn mint_asset_should_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ), Error::<Runtime>::NoPermission);
     })
 }
@@ -229,6 +249,10 @@ fn transfer_batch_should_work() {
             COLLECTION_ID,
             TokenType::Transferable,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
         assert_ok!(Nft::mint(
             origin.clone(),
@@ -236,7 +260,8 @@ fn transfer_batch_should_work() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ));
         assert_ok!(Nft::transfer_batch(origin, vec![(BOB,0),(BOB,1)]));
         let event = mock::Event::nft(crate::Event::TransferedNft(1, 2, 0));
@@ -255,6 +280,10 @@ fn transfer_batch_should_fail() {
             COLLECTION_ID,
             TokenType::Transferable,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
         assert_ok!(Nft::mint(
             origin.clone(),
@@ -262,7 +291,8 @@ fn transfer_batch_should_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ));
         assert_noop!(Nft::transfer_batch(origin.clone(), vec![(BOB,3),(BOB,4)]), Error::<Runtime>::AssetIdNotFound);
         //TODO add test case for ClassIdNotFound
@@ -323,6 +353,10 @@ fn do_transfer_should_fail() {
             COLLECTION_ID,
             TokenType::BoundToAddress,
             CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
         ));
         assert_ok!(Nft::mint(
             origin.clone(),
@@ -330,7 +364,8 @@ fn do_transfer_should_fail() {
             vec![1],
             vec![1],
             vec![1],
-            1
+            1,
+            vec![]
         ));
 
         assert_noop!(Nft::do_transfer(&ALICE, &BOB, 1), Error::<Runtime>::NonTransferable);
@@ -357,4 +392,1401 @@ fn do_check_nft_ownership_should_fail() {
         //TODO: ClassIdNotFound
         //TODO: add test case for AssetInfoNotFound
     })
-}
\ No newline at end of file
+}
+
+#[test]
+fn burn_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::burn(origin, 0));
+
+        assert_eq!(Nft::get_asset(0), None);
+        assert_eq!(Nft::get_assets_by_owner(ALICE), Vec::<u64>::new());
+        assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID), None);
+        assert_eq!(
+            reserved_balance(&class_id_account()),
+            <Runtime as Config>::CreateClassDeposit::get()
+        );
+        assert_eq!(free_balance(&ALICE), 100000 - <Runtime as Config>::CreateClassDeposit::get());
+
+        let event = mock::Event::nft(crate::Event::BurnedNft(ALICE, 0, 1));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn burn_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::AssetIdNotFound);
+
+        init_test_nft(origin.clone());
+
+        assert_noop!(Nft::burn(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn mint_asset_with_max_supply_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            Some(3),
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]));
+
+        let class_info = NftModule::<Runtime>::classes(CLASS_ID).unwrap();
+        assert_eq!(class_info.data.total_supply, 2);
+        assert_eq!(class_info.data.initial_supply, Some(3));
+
+        assert_noop!(
+            Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]),
+            Error::<Runtime>::ExceededMaxSupply
+        );
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+        assert_noop!(
+            Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]),
+            Error::<Runtime>::ExceededMaxSupply
+        );
+    })
+}
+
+#[test]
+fn failed_mint_should_not_use_up_supply() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            Some(3),
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        <Runtime as Config>::Currency::make_free_balance_be(&ALICE, 2);
+
+        assert_noop!(
+            Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 3, vec![]),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 0);
+    })
+}
+
+#[test]
+fn create_class_with_zero_max_supply_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_noop!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            Some(0),
+            Perbill::zero(),
+            None,
+            None,
+        ), Error::<Runtime>::InvalidQuantity);
+    })
+}
+
+#[test]
+fn create_class_with_royalty_should_fail_above_limit() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_noop!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            None,
+            Perbill::from_percent(26),
+            None,
+            None,
+        ), Error::<Runtime>::RoyaltyFeeExceedLimit);
+    })
+}
+
+#[test]
+fn transfer_class_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::transfer_class(origin.clone(), CLASS_ID, BOB));
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().owner, BOB);
+
+        let event = mock::Event::nft(crate::Event::TransferedNftClass(ALICE, BOB, CLASS_ID));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn transfer_class_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::transfer_class(origin.clone(), CLASS_ID, BOB), Error::<Runtime>::ClassIdNotFound);
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::transfer_class(Origin::signed(BOB), CLASS_ID, BOB), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn destroy_class_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::burn(origin.clone(), 0));
+        assert_ok!(Nft::destroy_class(origin.clone(), CLASS_ID));
+
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID), None);
+        assert_eq!(reserved_balance(&class_id_account()), 0);
+        assert_eq!(free_balance(&ALICE), 100000);
+
+        let event = mock::Event::nft(crate::Event::DestroyedNftClass(ALICE, CLASS_ID, 2));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn destroy_transferred_class_should_refund_creator() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::burn(origin.clone(), 0));
+        assert_eq!(Nft::get_class_depositor(CLASS_ID), Some(ALICE));
+        assert_ok!(Nft::transfer_class(origin.clone(), CLASS_ID, BOB));
+
+        assert_ok!(Nft::destroy_class(Origin::signed(BOB), CLASS_ID));
+        assert_eq!(reserved_balance(&class_id_account()), 0);
+        assert_eq!(free_balance(&ALICE), 100000);
+        assert_eq!(free_balance(&BOB), 0);
+        assert_eq!(Nft::get_class_depositor(CLASS_ID), None);
+
+        let event = mock::Event::nft(crate::Event::DestroyedNftClass(BOB, CLASS_ID, 2));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn destroy_class_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::destroy_class(origin.clone(), CLASS_ID), Error::<Runtime>::ClassIdNotFound);
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::destroy_class(Origin::signed(BOB), CLASS_ID), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::destroy_class(origin.clone(), CLASS_ID), Error::<Runtime>::CannotDestroyClass);
+    })
+}
+
+#[test]
+fn update_token_metadata_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::update_token_metadata(origin.clone(), 0, vec![2], vec![3], vec![4]));
+
+        let token_info = NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap();
+        assert_eq!(token_info.metadata, vec![4]);
+        assert_eq!(token_info.data.name, vec![2]);
+        assert_eq!(token_info.data.description, vec![3]);
+        assert_eq!(token_info.data.properties, vec![4]);
+
+        let event = mock::Event::nft(crate::Event::TokenMetadataUpdated(ALICE, 0));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn update_class_metadata_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![2]));
+
+        let class_info = NftModule::<Runtime>::classes(CLASS_ID).unwrap();
+        assert_eq!(class_info.metadata, vec![2]);
+        assert_eq!(class_info.data.metadata, vec![2]);
+
+        let event = mock::Event::nft(crate::Event::ClassMetadataUpdated(ALICE, CLASS_ID));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn update_metadata_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::update_token_metadata(origin.clone(), 0, vec![2], vec![2], vec![2]), Error::<Runtime>::AssetIdNotFound);
+        assert_noop!(Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![2]), Error::<Runtime>::ClassIdNotFound);
+
+        init_test_nft(origin.clone());
+
+        assert_noop!(Nft::update_token_metadata(Origin::signed(BOB), 0, vec![2], vec![2], vec![2]), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::update_class_metadata(Origin::signed(BOB), CLASS_ID, vec![2]), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn freeze_metadata_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::freeze_metadata(origin.clone(), MetadataTarget::Token(0)));
+        assert_eq!(Nft::is_metadata_frozen(MetadataTarget::Token(0)), true);
+
+        let event = mock::Event::nft(crate::Event::MetadataFrozen(ALICE, MetadataTarget::Token(0)));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::update_token_metadata(origin.clone(), 0, vec![2], vec![2], vec![2]), Error::<Runtime>::MetadataIsFrozen);
+        assert_noop!(Nft::freeze_metadata(origin.clone(), MetadataTarget::Token(0)), Error::<Runtime>::MetadataIsFrozen);
+
+        assert_ok!(Nft::freeze_metadata(origin.clone(), MetadataTarget::Class(CLASS_ID)));
+        assert_noop!(Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![2]), Error::<Runtime>::MetadataIsFrozen);
+    })
+}
+
+fn init_test_wearable(owner: Origin) {
+    init_test_nft(owner.clone());
+    assert_ok!(Nft::create_class(
+        owner.clone(),
+        vec![1],
+        COLLECTION_ID,
+        TokenType::Transferable,
+        CollectionType::Wearable,
+        None,
+        Perbill::zero(),
+        None,
+        None,
+    ));
+    assert_ok!(Nft::mint(owner.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+}
+
+#[test]
+fn equip_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_wearable(origin.clone());
+        assert_ok!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 1));
+
+        assert_eq!(Nft::get_equipped_item(0, WearableSlot::Hat), Some(1));
+        assert_eq!(Nft::get_equipped_avatar(1), Some(0));
+
+        let event = mock::Event::nft(crate::Event::WearableEquipped(ALICE, 0, WearableSlot::Hat, 1));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::transfer(origin.clone(), BOB, 1), Error::<Runtime>::AssetIsEquipped);
+        assert_noop!(Nft::burn(origin.clone(), 1), Error::<Runtime>::AssetIsEquipped);
+        assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::AvatarHasEquippedItems);
+    })
+}
+
+#[test]
+fn equip_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_wearable(origin.clone());
+        assert_noop!(Nft::equip(Origin::signed(BOB), 0, WearableSlot::Hat, 1), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::equip(origin.clone(), 1, WearableSlot::Hat, 0), Error::<Runtime>::InvalidAvatar);
+        assert_noop!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 0), Error::<Runtime>::NotWearable);
+
+        assert_ok!(Nft::mint(origin.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+        assert_ok!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 1));
+        assert_noop!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 2), Error::<Runtime>::SlotAlreadyOccupied);
+        assert_noop!(Nft::equip(origin.clone(), 0, WearableSlot::Shoes, 1), Error::<Runtime>::AssetIsEquipped);
+    })
+}
+
+#[test]
+fn unequip_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_wearable(origin.clone());
+        assert_noop!(Nft::unequip(origin.clone(), 0, WearableSlot::Hat), Error::<Runtime>::SlotIsEmpty);
+
+        assert_ok!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 1));
+        assert_ok!(Nft::unequip(origin.clone(), 0, WearableSlot::Hat));
+
+        assert_eq!(Nft::get_equipped_item(0, WearableSlot::Hat), None);
+        assert_eq!(Nft::get_equipped_avatar(1), None);
+
+        let event = mock::Event::nft(crate::Event::WearableUnequipped(ALICE, 0, WearableSlot::Hat, 1));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 1));
+    })
+}
+
+#[test]
+fn transfer_avatar_moves_equipped_items() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_wearable(origin.clone());
+        assert_ok!(Nft::equip(origin.clone(), 0, WearableSlot::Jacket, 1));
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+
+        assert_eq!(Nft::get_assets_by_owner(ALICE), Vec::<u64>::new());
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0, 1]);
+        assert_eq!(NftModule::<Runtime>::tokens(1, 0).unwrap().owner, BOB);
+        assert_eq!(Nft::get_equipped_item(0, WearableSlot::Jacket), Some(1));
+
+        assert_ok!(Nft::unequip(Origin::signed(BOB), 0, WearableSlot::Jacket));
+    })
+}
+
+#[test]
+fn equip_soulbound_wearable_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::BoundToAddress,
+            CollectionType::Wearable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        assert_ok!(Nft::mint(origin.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+
+        assert_noop!(Nft::equip(origin.clone(), 0, WearableSlot::Hat, 1), Error::<Runtime>::NonTransferable);
+
+        // Wearable equipped before equip rejected soulbound items does not leave with the avatar
+        EquippedItems::<Runtime>::insert(0, WearableSlot::Hat, 1);
+        EquippedOn::<Runtime>::insert(1, 0);
+        assert_noop!(Nft::transfer(origin.clone(), BOB, 0), Error::<Runtime>::NonTransferable);
+        assert_eq!(NftModule::<Runtime>::tokens(1, 0).unwrap().owner, ALICE);
+
+        assert_ok!(Nft::unequip(origin.clone(), 0, WearableSlot::Hat));
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+        assert_eq!(Nft::get_assets_by_owner(ALICE), vec![1]);
+    })
+}
+
+fn init_test_executable(owner: Origin) {
+    init_test_nft(owner.clone());
+    assert_ok!(Nft::create_class(
+        owner.clone(),
+        vec![1],
+        COLLECTION_ID,
+        TokenType::Transferable,
+        CollectionType::Executable,
+        None,
+        Perbill::zero(),
+        None,
+        None,
+    ));
+    assert_ok!(Nft::mint(owner.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+}
+
+#[test]
+fn bind_contract_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_executable(origin.clone());
+        assert_ok!(Nft::bind_contract(origin.clone(), 1, CONTRACT));
+        assert_eq!(Nft::get_class_contract(1), Some(CONTRACT));
+
+        let event = mock::Event::nft(crate::Event::ContractBound(1, CONTRACT));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn bind_contract_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_executable(origin.clone());
+        assert_noop!(Nft::bind_contract(Origin::signed(BOB), 1, CONTRACT), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::bind_contract(origin.clone(), CLASS_ID, CONTRACT), Error::<Runtime>::NotExecutable);
+    })
+}
+
+#[test]
+fn execute_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_executable(origin.clone());
+        assert_ok!(Nft::bind_contract(origin.clone(), 1, CONTRACT));
+        assert_ok!(Nft::execute(origin.clone(), 1, vec![1, 2, 3, 4], 1_000));
+
+        let event = mock::Event::nft(crate::Event::NftExecuted(ALICE, 1, CONTRACT));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn execute_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_executable(origin.clone());
+        assert_noop!(Nft::execute(origin.clone(), 1, vec![], 1_000), Error::<Runtime>::ContractNotBound);
+        assert_noop!(Nft::execute(origin.clone(), 0, vec![], 1_000), Error::<Runtime>::NotExecutable);
+
+        assert_ok!(Nft::bind_contract(origin.clone(), 1, CONTRACT));
+        assert_noop!(Nft::execute(Origin::signed(BOB), 1, vec![], 1_000), Error::<Runtime>::NoPermission);
+
+        assert_ok!(Nft::bind_contract(origin.clone(), 1, BOB));
+        assert_noop!(Nft::execute(origin.clone(), 1, vec![], 1_000), Error::<Runtime>::ContractExecutionFailed);
+    })
+}
+
+#[test]
+fn approve_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::approve(origin.clone(), 0, BOB));
+        assert_eq!(Nft::get_approved(0), Some(BOB));
+
+        let event = mock::Event::nft(crate::Event::Approval(ALICE, BOB, 0));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::cancel_approval(origin.clone(), 0));
+        assert_eq!(Nft::get_approved(0), None);
+
+        assert_noop!(Nft::approve(Origin::signed(BOB), 0, BOB), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn transfer_from_with_approval_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0), Error::<Runtime>::NoPermission);
+
+        assert_ok!(Nft::approve(origin.clone(), 0, BOB));
+        assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0));
+
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0]);
+        assert_eq!(Nft::get_approved(0), None);
+
+        let event = mock::Event::nft(crate::Event::TransferedNft(ALICE, BOB, 0));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn transfer_from_with_operator_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::set_approval_for_all(origin.clone(), BOB, true));
+        assert_eq!(Nft::is_approved_for_all(ALICE, BOB), true);
+
+        let event = mock::Event::nft(crate::Event::ApprovalForAll(ALICE, BOB, true));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0));
+        assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, 0));
+
+        assert_ok!(Nft::set_approval_for_all(origin.clone(), BOB, false));
+        assert_eq!(Nft::is_approved_for_all(ALICE, BOB), false);
+        assert_noop!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn assets_by_owner_should_paginate() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 4, vec![]));
+
+        assert_eq!(Nft::assets_by_owner(&ALICE, 0, 2), vec![0, 1]);
+        assert_eq!(Nft::assets_by_owner(&ALICE, 2, 2), vec![2, 3]);
+        assert_eq!(Nft::assets_by_owner(&ALICE, 4, 2), vec![4]);
+        assert_eq!(Nft::assets_by_owner(&BOB, 0, 2), Vec::<u64>::new());
+
+        // Page size is capped
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 100, vec![]));
+        assert_eq!(Nft::assets_by_owner(&ALICE, 0, u32::MAX).len(), MAX_ASSETS_PAGE_SIZE as usize);
+        assert_eq!(Nft::assets_by_owner(&ALICE, 100, u32::MAX), vec![100, 101, 102, 103, 104]);
+    })
+}
+
+#[test]
+fn asset_and_class_details_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+
+        let asset_details = Nft::asset_details(0).unwrap();
+        assert_eq!(asset_details.owner, ALICE);
+        assert_eq!(asset_details.class_id, CLASS_ID);
+        assert_eq!(asset_details.token_id, TOKEN_ID);
+        assert_eq!(asset_details.in_auction, false);
+        assert_eq!(Nft::asset_details(1), None);
+
+        let class_details = Nft::class_details(CLASS_ID).unwrap();
+        assert_eq!(class_details.owner, ALICE);
+        assert_eq!(class_details.total_issuance, 1);
+        assert_eq!(class_details.group_collection_id, Some(COLLECTION_ID));
+
+        let collection_data = NftGroupCollectionData {
+            name: vec![1],
+            properties: vec![1],
+        };
+        assert_eq!(Nft::class_group_collection(CLASS_ID), Some((COLLECTION_ID, collection_data)));
+        assert_eq!(Nft::class_group_collection(CLASS_ID_NOT_EXIST), None);
+    })
+}
+
+#[test]
+fn fractionalize_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::fractionalize(origin.clone(), 0, 1000));
+
+        let fractional_info = Nft::get_fractional_asset(0).unwrap();
+        let vault = Nft::fractional_vault();
+        assert_ne!(vault, class_id_account());
+        assert_eq!(fractional_info.total_shares, 1000);
+        assert_eq!(Tokens::free_balance(fractional_info.currency_id, &ALICE), 1000);
+        assert_eq!(Nft::check_nft_ownership(&vault, &0), Ok(true));
+        assert_eq!(Nft::get_assets_by_owner(vault), vec![0]);
+
+        let event = mock::Event::nft(crate::Event::NftFractionalized(ALICE, 0, fractional_info.currency_id, 1000));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::transfer(origin.clone(), BOB, 0), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn fractionalize_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::fractionalize(origin.clone(), 0, 0), Error::<Runtime>::InvalidQuantity);
+        assert_noop!(Nft::fractionalize(Origin::signed(BOB), 0, 1000), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::fractionalize(origin.clone(), 1, 1000), Error::<Runtime>::AssetIdNotFound);
+    })
+}
+
+#[test]
+fn escrow_accounts_should_not_collide_with_class_funds() {
+    ExtBuilder::default().build().execute_with(|| {
+        let class_fund = |class_id: u32| -> AccountId { <Runtime as Config>::ModuleId::get().into_sub_account(class_id) };
+
+        assert_ne!(Nft::fractional_vault(), class_fund(u32::from_le_bytes(*b"frac")));
+        assert_ne!(Nft::bundle_account(0), class_fund(u32::from_le_bytes(*b"bndl")));
+        assert_ne!(Nft::bundle_account(0), Nft::fractional_vault());
+    })
+}
+
+#[test]
+fn redeem_fractions_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::fractionalize(origin.clone(), 0, 1000));
+        let currency_id = Nft::get_fractional_asset(0).unwrap().currency_id;
+
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(currency_id, &ALICE, &BOB, 1000));
+        assert_ok!(Nft::redeem_fractions(Origin::signed(BOB), 0));
+
+        assert_eq!(Nft::get_fractional_asset(0), None);
+        assert_eq!(Tokens::total_issuance(currency_id), 0);
+        assert_eq!(Nft::check_nft_ownership(&BOB, &0), Ok(true));
+        assert_eq!(Nft::get_assets_by_owner(Nft::fractional_vault()), Vec::<u64>::new());
+
+        let event = mock::Event::nft(crate::Event::NftRedeemed(BOB, 0, currency_id));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, 0));
+    })
+}
+
+#[test]
+fn redeem_fractions_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::redeem_fractions(origin.clone(), 0), Error::<Runtime>::AssetNotFractionalized);
+
+        assert_ok!(Nft::fractionalize(origin.clone(), 0, 1000));
+        let currency_id = Nft::get_fractional_asset(0).unwrap().currency_id;
+        assert_ok!(<Tokens as MultiCurrency<AccountId>>::transfer(currency_id, &ALICE, &BOB, 1));
+        assert_noop!(Nft::redeem_fractions(origin.clone(), 0), Error::<Runtime>::InsufficientShares);
+    })
+}
+
+#[test]
+fn rental_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(<Tokens as MultiCurrencyExtended<AccountId>>::deposit(0, &BOB, 100));
+
+        assert_ok!(Nft::lend(origin.clone(), 0, BOB, 10, Some((0, 30))));
+        let event = mock::Event::nft(crate::Event::RentalOffered(ALICE, BOB, 0, 10));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::accept_rental(Origin::signed(BOB), 0));
+        assert_eq!(Nft::get_rental_offer(0), None);
+        assert_eq!(Nft::get_asset_user(0), Some(NftRentalInfo { user: BOB, expires_at: 11 }));
+        assert_eq!(Tokens::free_balance(0, &BOB), 70);
+        assert_eq!(Tokens::free_balance(0, &ALICE), 30);
+
+        let event = mock::Event::nft(crate::Event::NftRented(ALICE, BOB, 0, 11));
+        assert_eq!(last_event(), event);
+
+        // Owner keeps the asset but cannot move it while rented
+        assert_eq!(Nft::check_nft_ownership(&ALICE, &0), Ok(true));
+        assert_noop!(Nft::transfer(origin.clone(), BOB, 0), Error::<Runtime>::AssetIsRented);
+        assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::AssetIsRented);
+        assert_noop!(Nft::lend(origin.clone(), 0, BOB, 10, None), Error::<Runtime>::AssetIsRented);
+
+        run_to_block(11);
+        assert_eq!(Nft::get_asset_user(0), None);
+        let event = mock::Event::nft(crate::Event::RentalExpired(BOB, 0));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+    })
+}
+
+#[test]
+fn rental_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::lend(origin.clone(), 0, BOB, 0, None), Error::<Runtime>::InvalidRentalDuration);
+        assert_noop!(Nft::lend(Origin::signed(BOB), 0, BOB, 10, None), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::accept_rental(Origin::signed(BOB), 0), Error::<Runtime>::RentalOfferNotFound);
+
+        assert_ok!(Nft::lend(origin.clone(), 0, BOB, 10, Some((0, 30))));
+        assert_noop!(Nft::accept_rental(origin.clone(), 0), Error::<Runtime>::NoPermission);
+        assert!(Nft::accept_rental(Origin::signed(BOB), 0).is_err());
+        assert_eq!(Nft::get_asset_user(0), None);
+
+        assert_noop!(Nft::cancel_rental_offer(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
+        assert_ok!(Nft::cancel_rental_offer(origin.clone(), 0));
+        assert_noop!(Nft::accept_rental(Origin::signed(BOB), 0), Error::<Runtime>::RentalOfferNotFound);
+
+        assert_ok!(Nft::lend(origin.clone(), 0, BOB, u64::MAX, None));
+        assert_noop!(Nft::accept_rental(Origin::signed(BOB), 0), Error::<Runtime>::RentalDurationOverflow);
+    })
+}
+
+fn init_test_soulbound(owner: Origin) {
+    assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+    assert_ok!(Nft::create_class(
+        owner.clone(),
+        vec![1],
+        COLLECTION_ID,
+        TokenType::BoundToAddress,
+        CollectionType::Collectable,
+        None,
+        Perbill::zero(),
+        None,
+        None,
+    ));
+    assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, vec![]));
+}
+
+#[test]
+fn reissue_to_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_soulbound(origin.clone());
+
+        // Issuer consent
+        assert_ok!(Nft::reissue_to(origin.clone(), 0, BOB));
+        assert_eq!(Nft::check_nft_ownership(&BOB, &0), Ok(true));
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0]);
+        let event = mock::Event::nft(crate::Event::NftReissued(ALICE, BOB, 0));
+        assert_eq!(last_event(), event);
+
+        // Recovered account
+        assert_ok!(Nft::reissue_to(Origin::signed(RESCUER), 0, RESCUER));
+        assert_eq!(Nft::check_nft_ownership(&RESCUER, &0), Ok(true));
+        assert_eq!(Nft::get_assets_by_owner(BOB), Vec::<u64>::new());
+    })
+}
+
+#[test]
+fn reissue_to_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::reissue_to(origin.clone(), 0, BOB), Error::<Runtime>::NotBoundToAddress);
+
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::BoundToAddress,
+            CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        assert_ok!(Nft::mint(origin.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+        // Rescuer has not recovered the issuer account
+        assert_noop!(Nft::reissue_to(Origin::signed(RESCUER), 1, RESCUER), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::reissue_to(Origin::signed(BOB), 1, BOB), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn revoke_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_soulbound(origin.clone());
+        assert_ok!(Nft::reissue_to(origin.clone(), 0, BOB));
+        assert_eq!(free_balance(&ALICE), 99997);
+
+        assert_ok!(Nft::revoke(origin.clone(), 0));
+        assert_eq!(Nft::get_assets_by_owner(BOB), Vec::<u64>::new());
+        assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID), None);
+        // Deposit is refunded to the holder, not the issuer
+        assert_eq!(free_balance(&ALICE), 99997);
+        assert_eq!(free_balance(&BOB), 1);
+
+        let event = mock::Event::nft(crate::Event::NftRevoked(ALICE, BOB, 0));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn revoke_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::revoke(origin.clone(), 0), Error::<Runtime>::AssetIdNotFound);
+        init_test_soulbound(origin.clone());
+        assert_ok!(Nft::reissue_to(origin.clone(), 0, BOB));
+        assert_noop!(Nft::revoke(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn sign_asset_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+
+        assert_ok!(Nft::set_signing_fee(Origin::signed(BOB), 0, Some(10)));
+        assert_eq!(Nft::get_signing_fee(0), Some(10));
+        let event = mock::Event::nft(crate::Event::SigningFeeUpdated(BOB, 0, Some(10)));
+        assert_eq!(last_event(), event);
+
+        // Signer cannot skip the fee set by the owner
+        assert_noop!(
+            Nft::sign_asset(Origin::signed(RESCUER), 0),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+
+        assert_ok!(Nft::sign_asset(origin.clone(), 0));
+        assert_eq!(free_balance(&BOB), 10);
+        let event = mock::Event::nft(crate::Event::SignedNft(0, ALICE));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0));
+        assert_eq!(Nft::get_supporters(0), vec![ALICE, BOB]);
+
+        // Signatures stay with the asset, the fee is reset for the new owner
+        assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, 0));
+        assert_eq!(Nft::get_supporters(0), vec![ALICE, BOB]);
+        assert_eq!(Nft::get_signing_fee(0), None);
+    })
+}
+
+#[test]
+fn sign_asset_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::sign_asset(origin.clone(), 0), Error::<Runtime>::AssetIdNotFound);
+        init_test_nft(origin.clone());
+        assert_noop!(Nft::set_signing_fee(Origin::signed(BOB), 0, Some(10)), Error::<Runtime>::NoPermission);
+
+        assert_ok!(Nft::sign_asset(origin.clone(), 0));
+        assert_noop!(Nft::sign_asset(origin.clone(), 0), Error::<Runtime>::AlreadySigned);
+        assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0));
+        assert_noop!(Nft::sign_asset(Origin::signed(RESCUER), 0), Error::<Runtime>::ExceededMaxSupporters);
+    })
+}
+
+fn test_voucher(nonce: u64, expiry: BlockNumber) -> MintVoucher<u32, Balance, BlockNumber> {
+    MintVoucher {
+        class_id: CLASS_ID,
+        name: vec![1],
+        description: vec![1],
+        metadata: vec![1],
+        price: 10,
+        expiry,
+        nonce,
+    }
+}
+
+#[test]
+fn redeem_voucher_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+        assert_ok!(<Runtime as Config>::Currency::transfer(&ALICE, &BOB, 100, ExistenceRequirement::KeepAlive));
+
+        let voucher = test_voucher(7, 10);
+        let signature = MockSignature(ALICE, Nft::voucher_signing_payload(&voucher));
+        assert_ok!(Nft::redeem_voucher(Origin::signed(BOB), voucher, signature));
+
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0, 1]);
+        assert_eq!(Nft::is_voucher_used(CLASS_ID, 7), true);
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 2);
+        // Price to the creator, deposit reserved in the class fund
+        assert_eq!(free_balance(&BOB), 89);
+        assert_eq!(reserved_balance(&class_id_account()), 4);
+
+        let event = mock::Event::nft(crate::Event::VoucherRedeemed(BOB, CLASS_ID, 1, 7));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn redeem_voucher_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(<Runtime as Config>::Currency::transfer(&ALICE, &BOB, 100, ExistenceRequirement::KeepAlive));
+
+        let voucher = test_voucher(1, 10);
+        let forged = MockSignature(BOB, Nft::voucher_signing_payload(&voucher));
+        assert_noop!(Nft::redeem_voucher(Origin::signed(BOB), voucher.clone(), forged), Error::<Runtime>::InvalidVoucherSignature);
+
+        let tampered = MockSignature(ALICE, Nft::voucher_signing_payload(&test_voucher(2, 10)));
+        assert_noop!(Nft::redeem_voucher(Origin::signed(BOB), voucher.clone(), tampered), Error::<Runtime>::InvalidVoucherSignature);
+
+        // Signatures over the bare voucher are not accepted
+        let untagged = MockSignature(ALICE, voucher.encode());
+        assert_noop!(Nft::redeem_voucher(Origin::signed(BOB), voucher.clone(), untagged), Error::<Runtime>::InvalidVoucherSignature);
+
+        let signature = MockSignature(ALICE, Nft::voucher_signing_payload(&voucher));
+        assert_ok!(Nft::redeem_voucher(Origin::signed(BOB), voucher.clone(), signature.clone()));
+        assert_noop!(Nft::redeem_voucher(Origin::signed(BOB), voucher, signature), Error::<Runtime>::VoucherAlreadyUsed);
+
+        let expired = test_voucher(3, 10);
+        let signature = MockSignature(ALICE, Nft::voucher_signing_payload(&expired));
+        System::set_block_number(11);
+        assert_noop!(Nft::redeem_voucher(Origin::signed(BOB), expired, signature), Error::<Runtime>::VoucherExpired);
+    })
+}
+
+#[test]
+fn signed_group_lifecycle_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(origin.clone(), vec![1], vec![1]));
+        assert_eq!(Nft::get_group_collection_owner(0), Some((ALICE, 5)));
+        assert_eq!(reserved_balance(&ALICE), 5);
+
+        assert_ok!(Nft::update_group(origin.clone(), 0, vec![2], vec![2]));
+        let collection_data = NftGroupCollectionData {
+            name: vec![2],
+            properties: vec![2],
+        };
+        assert_eq!(Nft::get_group_collection(0), Some(collection_data));
+        let event = mock::Event::nft(crate::Event::NftGroupCollectionUpdated(0));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            0,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        assert_eq!(Nft::group_classes(0), vec![CLASS_ID]);
+        assert_noop!(Nft::remove_group(origin.clone(), 0), Error::<Runtime>::GroupIsNotEmpty);
+
+        assert_ok!(Nft::destroy_class(origin.clone(), CLASS_ID));
+        assert_eq!(Nft::group_classes(0), Vec::<u32>::new());
+
+        assert_ok!(Nft::remove_group(origin.clone(), 0));
+        assert_eq!(Nft::get_group_collection(0), None);
+        assert_eq!(Nft::get_group_collection_owner(0), None);
+        assert_eq!(Nft::all_nft_collection_count(), 0);
+        assert_eq!(reserved_balance(&ALICE), 0);
+
+        let event = mock::Event::nft(crate::Event::NftGroupCollectionRemoved(0));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn create_group_without_deposit_should_fail() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Nft::create_group(Origin::signed(BOB), vec![1], vec![1]),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+        assert_eq!(Nft::get_group_collection(0), None);
+        assert_eq!(Nft::all_nft_collection_count(), 0);
+    })
+}
+
+#[test]
+fn group_management_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::update_group(origin.clone(), 0, vec![2], vec![2]), Error::<Runtime>::CollectionIsNotExist);
+        assert_ok!(Nft::create_group(origin.clone(), vec![1], vec![1]));
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+
+        assert_noop!(Nft::update_group(Origin::signed(BOB), 0, vec![2], vec![2]), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::remove_group(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
+        // Only the group owner can add classes to a signed group
+        assert_noop!(
+            Nft::create_class(Origin::signed(BOB), vec![1], 0, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, None),
+            Error::<Runtime>::NoPermission
+        );
+        // Root owned group can only be managed by root
+        assert_noop!(Nft::remove_group(origin.clone(), 1), Error::<Runtime>::NoPermission);
+        assert_ok!(Nft::remove_group(Origin::root(), 1));
+        // Creator without balance for the deposit
+        assert!(Nft::create_group(Origin::signed(BOB), vec![1], vec![1]).is_err());
+    })
+}
+
+#[test]
+fn mint_batch_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        let items = vec![
+            (vec![1], vec![1], vec![1]),
+            (vec![2], vec![2], vec![2]),
+        ];
+        assert_ok!(Nft::mint_batch(origin.clone(), CLASS_ID, items, Some(vec![BOB, RESCUER])));
+
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![1]);
+        assert_eq!(Nft::get_assets_by_owner(RESCUER), vec![2]);
+        let token = NftModule::<Runtime>::tokens(CLASS_ID, 2).unwrap();
+        assert_eq!(token.metadata, vec![2]);
+        assert_eq!(token.data.name, vec![2]);
+        assert_eq!(reserved_balance(&class_id_account()), 5);
+
+        let event = mock::Event::nft(crate::Event::NewNftMinted(1, 2, ALICE, CLASS_ID, 2, 2));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::mint_batch(origin.clone(), CLASS_ID, vec![(vec![3], vec![3], vec![3])], None));
+        assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 3]);
+    })
+}
+
+#[test]
+fn mint_batch_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        let item = (vec![1], vec![1], vec![1]);
+        assert_noop!(Nft::mint_batch(origin.clone(), CLASS_ID, vec![], None), Error::<Runtime>::InvalidQuantity);
+        assert_noop!(Nft::mint_batch(origin.clone(), CLASS_ID, vec![item.clone(); 4], None), Error::<Runtime>::ExceededMaxBatchMint);
+        assert_noop!(Nft::mint_batch(origin.clone(), CLASS_ID, vec![item.clone(); 2], Some(vec![BOB])), Error::<Runtime>::RecipientsMismatch);
+        assert_noop!(Nft::mint_batch(Origin::signed(BOB), CLASS_ID, vec![item.clone()], None), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::mint_batch(origin.clone(), CLASS_ID_NOT_EXIST, vec![item], None), Error::<Runtime>::ClassIdNotFound);
+    })
+}
+
+#[test]
+fn custom_mint_deposit_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::Transferable,
+            CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            Some(10),
+        ));
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.mint_deposit, 10);
+
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]));
+        assert_eq!(reserved_balance(&class_id_account()), 22);
+        assert_eq!(free_balance(&ALICE), 100000 - 22);
+
+        // Burn refunds exactly what was charged for the token
+        assert_ok!(Nft::burn(origin.clone(), 0));
+        assert_eq!(reserved_balance(&class_id_account()), 12);
+        assert_eq!(free_balance(&ALICE), 100000 - 12);
+        let event = mock::Event::nft(crate::Event::BurnedNft(ALICE, 0, 10));
+        assert_eq!(last_event(), event);
+    })
+}
+
+#[test]
+fn set_deposit_minimums_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(Nft::set_deposit_minimums(origin.clone(), 3, 5), DispatchError::BadOrigin);
+        assert_ok!(Nft::set_deposit_minimums(Origin::root(), 3, 5));
+        assert_eq!(Nft::class_deposit_minimum(), 3);
+        assert_eq!(Nft::asset_deposit_minimum(), 5);
+        let event = mock::Event::nft(crate::Event::DepositMinimumsUpdated(3, 5));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_noop!(
+            Nft::create_class(origin.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, Some(4)),
+            Error::<Runtime>::DepositBelowMinimum
+        );
+        assert_noop!(
+            Nft::create_class(origin.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, Some(1001)),
+            Error::<Runtime>::DepositAboveMaximum
+        );
+        assert_noop!(Nft::set_deposit_minimums(Origin::root(), 3, 1001), Error::<Runtime>::DepositAboveMaximum);
+        assert_ok!(Nft::create_class(origin.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, None));
+
+        let class_data = NftModule::<Runtime>::classes(CLASS_ID).unwrap().data;
+        assert_eq!(class_data.deposit, 3);
+        assert_eq!(class_data.mint_deposit, 5);
+    })
+}
+
+#[test]
+fn raised_deposit_minimum_should_apply_to_existing_classes() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_eq!(reserved_balance(&class_id_account()), 3);
+
+        assert_ok!(Nft::set_deposit_minimums(Origin::root(), 2, 5));
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.mint_deposit, 1);
+
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]));
+        assert_eq!(reserved_balance(&class_id_account()), 13);
+        assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 1).unwrap().data.deposit, 5);
+
+        // Burn refunds what was charged for the token
+        assert_ok!(Nft::burn(origin.clone(), 1));
+        assert_eq!(reserved_balance(&class_id_account()), 8);
+        let event = mock::Event::nft(crate::Event::BurnedNft(ALICE, 1, 5));
+        assert_eq!(last_event(), event);
+    })
+}
+
+fn test_schema() -> Vec<(Vec<u8>, AttributeType)> {
+    vec![
+        (b"power".to_vec(), AttributeType::U32),
+        (b"shiny".to_vec(), AttributeType::Bool),
+        (b"rarity".to_vec(), AttributeType::Enum(vec![b"common".to_vec(), b"rare".to_vec()])),
+    ]
+}
+
+#[test]
+fn attributes_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(Nft::create_class(origin.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, None));
+        assert_ok!(Nft::set_class_schema(origin.clone(), CLASS_ID, test_schema()));
+        assert_eq!(Nft::get_class_schema(CLASS_ID), test_schema());
+
+        let attributes = vec![
+            (b"power".to_vec(), AttributeValue::U32(10)),
+            (b"rarity".to_vec(), AttributeValue::Enum(b"rare".to_vec())),
+        ];
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 1, attributes));
+        assert_eq!(Nft::get_token_attribute(0, b"power".to_vec()), Some(AttributeValue::U32(10)));
+        assert_eq!(<Nft as NftAttributes>::attribute(0, b"rarity"), Some(AttributeValue::Enum(b"rare".to_vec())));
+
+        // Class owner updates attributes after transfer
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+        assert_ok!(Nft::set_token_attributes(origin.clone(), 0, vec![(b"shiny".to_vec(), AttributeValue::Bool(true))]));
+        assert_eq!(<Nft as NftAttributes>::attributes(0).len(), 3);
+        let event = mock::Event::nft(crate::Event::TokenAttributesUpdated(ALICE, 0));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::burn(Origin::signed(BOB), 0));
+        assert_eq!(<Nft as NftAttributes>::attributes(0), vec![]);
+    })
+}
+
+#[test]
+fn attributes_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+        assert_ok!(Nft::create_class(origin.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, None, Perbill::zero(), None, None));
+
+        let duplicated = vec![(b"power".to_vec(), AttributeType::U32), (b"power".to_vec(), AttributeType::Bool)];
+        assert_noop!(Nft::set_class_schema(origin.clone(), CLASS_ID, duplicated), Error::<Runtime>::InvalidAttributeSchema);
+        let long_key = vec![(b"too_long_key".to_vec(), AttributeType::U32)];
+        assert_noop!(Nft::set_class_schema(origin.clone(), CLASS_ID, long_key), Error::<Runtime>::InvalidAttributeSchema);
+        let empty_enum = vec![(b"rarity".to_vec(), AttributeType::Enum(vec![]))];
+        assert_noop!(Nft::set_class_schema(origin.clone(), CLASS_ID, empty_enum), Error::<Runtime>::InvalidAttributeSchema);
+        assert_noop!(Nft::set_class_schema(Origin::signed(BOB), CLASS_ID, test_schema()), Error::<Runtime>::NoPermission);
+
+        assert_ok!(Nft::set_class_schema(origin.clone(), CLASS_ID, test_schema()));
+        let mint = |attributes| Nft::mint(Origin::signed(ALICE), CLASS_ID, vec![1], vec![1], vec![1], 1, attributes);
+        assert_noop!(mint(vec![(b"speed".to_vec(), AttributeValue::U32(1))]), Error::<Runtime>::UnknownAttribute);
+        assert_noop!(mint(vec![(b"power".to_vec(), AttributeValue::Bool(true))]), Error::<Runtime>::AttributeTypeMismatch);
+        assert_noop!(mint(vec![(b"rarity".to_vec(), AttributeValue::Enum(b"epic".to_vec()))]), Error::<Runtime>::AttributeTypeMismatch);
+
+        assert_ok!(mint(vec![]));
+        assert_noop!(Nft::set_class_schema(origin.clone(), CLASS_ID, test_schema()), Error::<Runtime>::SchemaInUse);
+        assert_noop!(Nft::set_token_attributes(Origin::signed(BOB), 0, vec![]), Error::<Runtime>::NoPermission);
+    })
+}
+
+fn test_sale(allowlist: Option<SaleAllowlist<AccountId>>) -> SaleConfig<AccountId, BlockNumber> {
+    SaleConfig {
+        currency_id: 0,
+        price: 10,
+        name: vec![1],
+        description: vec![1],
+        metadata: vec![1],
+        start: 5,
+        public_start: 10,
+        end: 20,
+        max_per_account: 2,
+        allowlist,
+    }
+}
+
+fn init_test_sale(owner: Origin, max_supply: Option<u64>, allowlist: Option<SaleAllowlist<AccountId>>) {
+    assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+    assert_ok!(Nft::create_class(owner.clone(), vec![1], COLLECTION_ID, TokenType::Transferable, CollectionType::Collectable, max_supply, Perbill::zero(), None, None));
+    assert_ok!(Nft::set_sale(owner, CLASS_ID, test_sale(allowlist)));
+    assert_ok!(<Runtime as Config>::Currency::transfer(&ALICE, &BOB, 100, ExistenceRequirement::KeepAlive));
+    assert_ok!(<Tokens as MultiCurrencyExtended<AccountId>>::deposit(0, &BOB, 100));
+}
+
+#[test]
+fn purchase_mint_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_sale(origin.clone(), None, Some(SaleAllowlist::Accounts(vec![BOB])));
+
+        System::set_block_number(5);
+        assert_ok!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 2, None));
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0, 1]);
+        assert_eq!(Nft::get_sale_purchases(CLASS_ID, BOB), 2);
+        assert_eq!(Tokens::free_balance(0, &BOB), 80);
+        assert_eq!(Tokens::free_balance(0, &ALICE), 20);
+        assert_eq!(free_balance(&BOB), 98);
+
+        let event = mock::Event::nft(crate::Event::SalePurchased(BOB, CLASS_ID, 0, 1, 2));
+        assert_eq!(last_event(), event);
+
+        assert_noop!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 1, None), Error::<Runtime>::ExceededPurchaseLimit);
+    })
+}
+
+#[test]
+fn purchase_mint_phases_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        let leaf_bob = H256::from(blake2_256(&BOB.encode()));
+        let leaf_rescuer = H256::from(blake2_256(&RESCUER.encode()));
+        let root = if leaf_bob <= leaf_rescuer {
+            H256::from(blake2_256(&(leaf_bob, leaf_rescuer).encode()))
+        } else {
+            H256::from(blake2_256(&(leaf_rescuer, leaf_bob).encode()))
+        };
+        init_test_sale(origin.clone(), Some(3), Some(SaleAllowlist::MerkleRoot(root)));
+        assert_ok!(<Runtime as Config>::Currency::transfer(&ALICE, &RESCUER, 100, ExistenceRequirement::KeepAlive));
+
+        assert_noop!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 1, Some(vec![leaf_rescuer])), Error::<Runtime>::SaleNotActive);
+
+        System::set_block_number(5);
+        assert_noop!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 1, None), Error::<Runtime>::NotInAllowlist);
+        assert_noop!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 1, Some(vec![leaf_bob])), Error::<Runtime>::NotInAllowlist);
+        assert_ok!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 2, Some(vec![leaf_rescuer])));
+        assert_noop!(Nft::purchase_mint(Origin::signed(ALICE), CLASS_ID, 1, None), Error::<Runtime>::NotInAllowlist);
+
+        // Public phase, RESCUER has no currency 0 tokens to pay with
+        System::set_block_number(10);
+        assert!(Nft::purchase_mint(Origin::signed(RESCUER), CLASS_ID, 1, None).is_err());
+        assert_ok!(<Tokens as MultiCurrencyExtended<AccountId>>::deposit(0, &RESCUER, 100));
+        assert_noop!(Nft::purchase_mint(Origin::signed(RESCUER), CLASS_ID, 2, None), Error::<Runtime>::ExceededMaxSupply);
+        assert_ok!(Nft::purchase_mint(Origin::signed(RESCUER), CLASS_ID, 1, None));
+
+        System::set_block_number(21);
+        assert_noop!(Nft::purchase_mint(Origin::signed(ALICE), CLASS_ID, 1, None), Error::<Runtime>::SaleNotActive);
+    })
+}
+
+#[test]
+fn set_sale_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_sale(origin.clone(), None, None);
+        let mut sale = test_sale(None);
+        sale.public_start = 30;
+        assert_noop!(Nft::set_sale(origin.clone(), CLASS_ID, sale), Error::<Runtime>::InvalidSaleConfig);
+        assert_noop!(Nft::set_sale(Origin::signed(BOB), CLASS_ID, test_sale(None)), Error::<Runtime>::NoPermission);
+
+        assert_ok!(Nft::cancel_sale(origin.clone(), CLASS_ID));
+        System::set_block_number(10);
+        assert_noop!(Nft::purchase_mint(Origin::signed(BOB), CLASS_ID, 1, None), Error::<Runtime>::SaleNotFound);
+    })
+}
+
+#[test]
+fn bundle_should_work() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 2, vec![]));
+
+        assert_ok!(Nft::bundle(origin.clone(), 0, vec![1, 2]));
+        let escrow = Nft::bundle_account(0);
+        assert_eq!(Nft::get_bundle_children(0), Some(vec![1, 2]));
+        assert_eq!(Nft::get_bundle_parent(1), Some(0));
+        assert_eq!(Nft::get_assets_by_owner(escrow), vec![1, 2]);
+        assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0]);
+        let event = mock::Event::nft(crate::Event::NftBundled(ALICE, 0, vec![1, 2]));
+        assert_eq!(last_event(), event);
+
+        // Children follow the parent
+        assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+        assert_noop!(Nft::burn(Origin::signed(BOB), 0), Error::<Runtime>::AssetHasBundle);
+
+        assert_ok!(Nft::unbundle(Origin::signed(BOB), 0));
+        assert_eq!(Nft::get_assets_by_owner(BOB), vec![0, 1, 2]);
+        assert_eq!(Nft::get_bundle_children(0), None);
+        assert_eq!(Nft::get_bundle_parent(1), None);
+        let event = mock::Event::nft(crate::Event::NftUnbundled(BOB, 0));
+        assert_eq!(last_event(), event);
+
+        assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, 1));
+    })
+}
+
+#[test]
+fn bundle_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], vec![1], vec![1], 3, vec![]));
+
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![]), Error::<Runtime>::InvalidBundle);
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![0]), Error::<Runtime>::InvalidBundle);
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![1, 1]), Error::<Runtime>::InvalidBundle);
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![1, 2, 3]), Error::<Runtime>::InvalidBundle);
+        assert_noop!(Nft::bundle(Origin::signed(BOB), 0, vec![1]), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::unbundle(origin.clone(), 0), Error::<Runtime>::BundleNotFound);
+
+        assert_ok!(Nft::bundle(origin.clone(), 0, vec![1]));
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![2, 3]), Error::<Runtime>::InvalidBundle);
+        assert_noop!(Nft::bundle(origin.clone(), 2, vec![1]), Error::<Runtime>::NoPermission);
+        assert_noop!(Nft::unbundle(Origin::signed(BOB), 0), Error::<Runtime>::NoPermission);
+    })
+}
+
+#[test]
+fn bundle_soulbound_child_should_fail() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        init_test_nft(origin.clone());
+        assert_ok!(Nft::create_class(
+            origin.clone(),
+            vec![1],
+            COLLECTION_ID,
+            TokenType::BoundToAddress,
+            CollectionType::Collectable,
+            None,
+            Perbill::zero(),
+            None,
+            None,
+        ));
+        assert_ok!(Nft::mint(origin.clone(), 1, vec![1], vec![1], vec![1], 1, vec![]));
+
+        assert_noop!(Nft::bundle(origin.clone(), 0, vec![1]), Error::<Runtime>::NonTransferable);
+        assert_eq!(Nft::get_bundle_parent(1), None);
+        assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 1]);
+    })
+}
+
+#[test]
+fn on_runtime_upgrade_should_migrate_old_classes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let old_class = orml_nft::ClassInfo {
+            metadata: vec![1],
+            total_issuance: 2u64,
+            owner: ALICE,
+            data: OldNftClassData {
+                deposit: 2u128,
+                metadata: vec![1],
+                token_type: TokenType::Transferable,
+                collection_type: CollectionType::Collectable,
+                total_supply: 0,
+                initial_supply: 0,
+            },
+        };
+        frame_support::storage::unhashed::put(&orml_nft::Classes::<Runtime>::hashed_key_for(CLASS_ID), &old_class);
+        ClassDataCollection::<Runtime>::insert(CLASS_ID, COLLECTION_ID);
+        // Chain launched before the pallet recorded a storage version
+        StorageVersion::<Runtime>::kill();
+        assert_eq!(Nft::storage_version(), Releases::V1_0_0);
+
+        <Nft as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        let class_info = NftModule::<Runtime>::classes(CLASS_ID).unwrap();
+        assert_eq!(class_info.owner, ALICE);
+        assert_eq!(class_info.data.deposit, 2);
+        assert_eq!(class_info.data.mint_deposit, 1);
+        assert_eq!(class_info.data.total_supply, 2);
+        assert_eq!(class_info.data.initial_supply, None);
+        assert_eq!(class_info.data.royalty_fee, Perbill::zero());
+        assert_eq!(class_info.data.royalty_beneficiary, ALICE);
+        assert_eq!(Nft::group_classes(COLLECTION_ID), vec![CLASS_ID]);
+        assert_eq!(Nft::get_class_depositor(CLASS_ID), Some(ALICE));
+        assert_eq!(Nft::storage_version(), Releases::V2_0_0);
+    })
+}
+
+#[test]
+fn on_runtime_upgrade_should_keep_genesis_classes() {
+    let origin = Origin::signed(ALICE);
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(Nft::storage_version(), Releases::V2_0_0);
+        init_test_nft(origin.clone());
+        let class_info = NftModule::<Runtime>::classes(CLASS_ID).unwrap();
+
+        <Nft as frame_support::traits::OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        assert_eq!(NftModule::<Runtime>::classes(CLASS_ID), Some(class_info));
+        assert_eq!(Nft::storage_version(), Releases::V2_0_0);
+    })
+}
@@ -0,0 +1,46 @@
+use crate::AssetId;
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Type of an attribute declared in a class schema
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttributeType {
+    U32,
+    Bool,
+    String,
+    // Allowed variant names
+    Enum(Vec<Vec<u8>>),
+}
+
+/// Value of a token attribute, checked against the class schema
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttributeValue {
+    U32(u32),
+    Bool(bool),
+    String(Vec<u8>),
+    // Selected variant name
+    Enum(Vec<u8>),
+}
+
+impl AttributeValue {
+    pub fn matches(&self, attribute_type: &AttributeType) -> bool {
+        match (self, attribute_type) {
+            (AttributeValue::U32(_), AttributeType::U32) => true,
+            (AttributeValue::Bool(_), AttributeType::Bool) => true,
+            (AttributeValue::String(_), AttributeType::String) => true,
+            (AttributeValue::Enum(variant), AttributeType::Enum(variants)) => variants.contains(variant),
+            _ => false,
+        }
+    }
+}
+
+pub trait NftAttributes {
+    fn attribute(asset_id: AssetId, key: &[u8]) -> Option<AttributeValue>;
+    fn attributes(asset_id: AssetId) -> Vec<(Vec<u8>, AttributeValue)>;
+}
@@ -19,7 +19,7 @@
 
 
 use sp_runtime::{
-    generic, traits::{Verify, BlakeTwo256, IdentifyAccount}, OpaqueExtrinsic, MultiSignature,
+    generic, traits::{Verify, BlakeTwo256, IdentifyAccount}, OpaqueExtrinsic, MultiSignature, DispatchError,
 };
 use codec::{Decode, Encode};
 use sp_runtime::RuntimeDebug;
@@ -27,6 +27,7 @@ use sp_runtime::RuntimeDebug;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+pub mod attributes;
 pub mod continuum;
 
 /// An index to a block.
@@ -77,6 +78,10 @@ pub type CurrencyId = u32;
 pub type GroupCollectionId = u64;
 /// AssetId for all NFT and FT
 pub type AssetId = u64;
+/// NFT class id type
+pub type ClassId = u32;
+/// NFT token id type
+pub type TokenId = u64;
 /// AuctionId
 pub type AuctionId = u64;
 /// SpotId
@@ -96,6 +101,11 @@ pub enum ItemId {
     Block(u64),
 }
 
+/// Source of new currency ids, shared by every pallet issuing fungible tokens
+pub trait CurrencyIdAllocator {
+    fn allocate_currency_id() -> Result<CurrencyId, DispatchError>;
+}
+
 /// App-specific crypto used for reporting equivocation/misbehavior in BABE and
 /// GRANDPA. Any rewards for misbehavior reporting will be paid out to this
 /// account.
@@ -26,7 +26,7 @@ use frame_support::{
 };
 use frame_system::{self as system, ensure_signed};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use primitives::{Balance, CountryId, CurrencyId};
+use primitives::{Balance, CountryId, CurrencyId, CurrencyIdAllocator};
 use sp_runtime::{
     traits::{AtLeast32Bit, One, StaticLookup, Zero, AccountIdConversion},
     DispatchError
@@ -145,18 +145,7 @@ pub mod pallet {
             );
 
             //Generate new CurrencyId
-            let currency_id = NextTokenId::<T>::mutate(|id| -> Result<CurrencyId, DispatchError>{
-                let current_id =*id;
-                if current_id == 0 {
-                   *id = 2;
-                    Ok(One::one())
-                }
-                else{
-                    *id = id.checked_add(One::one())
-                    .ok_or(Error::<T>::NoAvailableTokenId)?;
-                    Ok(current_id)
-                }
-            })?;
+            let currency_id = Self::allocate_currency_id()?;
             let fund_id: T::AccountId = T::SocialTokenTreasury::get().into_sub_account(country_id);
 
             //Country treasury
@@ -258,4 +247,18 @@ impl<T: Config> Module<T> {
     }
 }
 
-
+impl<T: Config> CurrencyIdAllocator for Module<T> {
+    fn allocate_currency_id() -> Result<CurrencyId, DispatchError> {
+        NextTokenId::<T>::mutate(|id| -> Result<CurrencyId, DispatchError> {
+            let current_id = *id;
+            if current_id == 0 {
+                *id = 2;
+                Ok(One::one())
+            } else {
+                *id = id.checked_add(One::one())
+                    .ok_or(Error::<T>::NoAvailableTokenId)?;
+                Ok(current_id)
+            }
+        })
+    }
+}
@@ -28,7 +28,7 @@ use sp_core::{
     OpaqueMetadata,
 };
 pub use primitives::{AccountId, Signature};
-use primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment, CurrencyId, Amount};
+use primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment, CurrencyId, Amount, AssetId, ClassId, TokenId, GroupCollectionId};
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
     Permill, Perbill, Perquintill, Percent, ApplyExtrinsicResult,
@@ -103,7 +103,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // and set impl_version to 0. If only runtime
     // implementation changes and behavior does not, then leave spec_version as
     // is and increment impl_version.
-    spec_version: 275,
+    spec_version: 276,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 2,
@@ -661,6 +661,7 @@ parameter_types! {
 	pub const BountyValueMinimum: Balance = 5 * DOLLARS;
     pub const CountryFundModuleId: ModuleId = ModuleId(*b"bit/fund");
     pub const NftModuleId: ModuleId = ModuleId(*b"bit/bnft");
+    pub const NftEscrowModuleId: ModuleId = ModuleId(*b"bit/nfte");
     pub const ContinuumTreasuryModuleId: ModuleId = ModuleId(*b"bit/ctmu");
     pub const LandTreasuryModuleId: ModuleId = ModuleId(*b"bit/land");
 }
@@ -1048,23 +1049,77 @@ impl orml_currencies::Config for Runtime {
 parameter_types! {
     pub CreateClassDeposit: Balance = 500 * MILLICENTS;
     pub CreateAssetDeposit: Balance = 100 * MILLICENTS;
+    pub CreateGroupDeposit: Balance = 500 * MILLICENTS;
+    pub MaxMintDeposit: Balance = 100 * DOLLARS;
+    pub const MaxRoyaltyFee: Perbill = Perbill::from_percent(25);
+    pub const TransferEquippedWithAvatar: bool = true;
+    pub const MaxSupporters: u32 = 100;
+    pub const MaxBatchMint: u32 = 50;
+    pub const MaxBundleSize: u32 = 20;
+    pub const MaxClassAttributes: u32 = 32;
+    pub const MaxAttributeKeyLength: u32 = 32;
+    pub const MaxAttributeValueLength: u32 = 64;
+}
+
+pub struct NftContractExecutor;
+
+impl nft::ContractExecutor<AccountId> for NftContractExecutor {
+    fn call_contract(
+        caller: AccountId,
+        contract: AccountId,
+        input_data: Vec<u8>,
+        gas_limit: Weight,
+    ) -> (bool, Weight) {
+        let result = Contracts::bare_call(caller, contract, 0, gas_limit, input_data);
+        let success = match result.exec_result {
+            Ok(ref return_value) => return_value.is_success(),
+            Err(_) => false,
+        };
+
+        (success, result.gas_consumed)
+    }
+}
+
+pub struct NftAccountRecovery;
+
+impl nft::AccountRecovery<AccountId> for NftAccountRecovery {
+    fn is_recovered_by(lost: &AccountId, rescuer: &AccountId) -> bool {
+        Recovery::proxy(rescuer).as_ref() == Some(lost)
+    }
 }
 
 impl nft::Config for Runtime {
     type Event = Event;
     type CreateClassDeposit = CreateClassDeposit;
     type CreateAssetDeposit = CreateAssetDeposit;
+    type CreateGroupDeposit = CreateGroupDeposit;
+    type MaxMintDeposit = MaxMintDeposit;
+    type MaxRoyaltyFee = MaxRoyaltyFee;
+    type TransferEquippedWithAvatar = TransferEquippedWithAvatar;
+    type MaxSupporters = MaxSupporters;
+    type MaxBatchMint = MaxBatchMint;
+    type MaxBundleSize = MaxBundleSize;
+    type MaxClassAttributes = MaxClassAttributes;
+    type MaxAttributeKeyLength = MaxAttributeKeyLength;
+    type MaxAttributeValueLength = MaxAttributeValueLength;
+    type ContractExecutor = NftContractExecutor;
+    type FungibleTokenCurrency = Currencies;
+    type CurrencyIdAllocator = TokenizationModule;
+    type AccountRecovery = NftAccountRecovery;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as traits::Verify>::Signer;
     type Currency = Balances;
     type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
     type ModuleId = NftModuleId;
+    type EscrowModuleId = NftEscrowModuleId;
     type AuctionHandler = Auction;
     type AssetsHandler = NftModule;
 }
 
 impl orml_nft::Config for Runtime {
-    type ClassId = u32;
-    type TokenId = u64;
-    type ClassData = nft::NftClassData<Balance>;
+    type ClassId = ClassId;
+    type TokenId = TokenId;
+    type ClassData = nft::NftClassData<Balance, AccountId>;
     type TokenData = nft::NftAssetData<Balance>;
 }
 
@@ -1087,6 +1142,11 @@ impl block::Config for Runtime {
 
 parameter_types! {
     pub const AuctionTimeToClose: u32 = 100800; //Default 100800 Blocks
+    pub const AntiSnipeDuration: BlockNumber = 50; //Bids in the last 50 blocks extend the auction
+    pub const MaxAuctionExtension: BlockNumber = 14400; //Extend by at most 1 day in total
+    pub const SealedBidRevealPeriod: BlockNumber = 14400; //Last day of sealed bid auctions is for revealing
+    pub const MinimumSealedBidDeposit: Balance = 1 * DOLLARS;
+    pub const MaxSealedBids: u32 = 100;
     pub const ContinuumSessionDuration: BlockNumber = 43200; //Default 43200 Blocks
     pub const SpotAuctionChillingDuration: BlockNumber = 43200; //Default 43200 Blocks
 }
@@ -1098,6 +1158,12 @@ impl auction::Config for Runtime {
     type Handler = Auction;
     type Currency = Balances;
     type ContinuumHandler = Continuum;
+    type AntiSnipeDuration = AntiSnipeDuration;
+    type MaxAuctionExtension = MaxAuctionExtension;
+    type SealedBidRevealPeriod = SealedBidRevealPeriod;
+    type MinimumSealedBidDeposit = MinimumSealedBidDeposit;
+    type MaxSealedBids = MaxSealedBids;
+    type TreasuryModuleId = BitCountryTreasuryModuleId;
 }
 
 impl continuum::Config for Runtime {
@@ -1182,7 +1248,7 @@ construct_runtime!(
         BitCountryModule: bitcountry::{Module, Call, Storage, Event<T>},
         BlockModule: block::{Module, Call, Storage, Event<T>},
         OrmlNFT: orml_nft::{Module, Storage},
-        NftModule: nft::{Module, Call, Storage, Event<T>},
+        NftModule: nft::{Module, Call, Storage, Config, Event<T>},
         Continuum: continuum::{Module, Call, Storage, Config<T>, Event<T>},
         Auction: auction::{Module, Call ,Storage, Event<T>},
         Currencies: orml_currencies::{ Module, Storage, Call, Event<T>},
@@ -1394,6 +1460,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_nft_rpc_runtime_api::NftApi<Block, AccountId, ClassId, TokenId, Balance> for Runtime {
+		fn assets_by_owner(owner: AccountId, start: u32, limit: u32) -> Vec<AssetId> {
+			NftModule::assets_by_owner(&owner, start, limit)
+		}
+
+		fn asset_details(asset_id: AssetId) -> Option<nft::NftAssetDetails<AccountId, ClassId, TokenId, Balance>> {
+			NftModule::asset_details(asset_id)
+		}
+
+		fn class_details(class_id: ClassId) -> Option<nft::NftClassDetails<AccountId, TokenId, Balance>> {
+			NftModule::class_details(class_id)
+		}
+
+		fn class_group_collection(class_id: ClassId) -> Option<(GroupCollectionId, nft::NftGroupCollectionData)> {
+			NftModule::class_group_collection(class_id)
+		}
+	}
+
 	impl pallet_contracts_rpc_runtime_api::ContractsApi<Block, AccountId, Balance, BlockNumber>
 		for Runtime
 	{
@@ -28,9 +28,41 @@ pub enum Change<Value> {
 
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub enum AuctionType {
+pub enum AuctionType<Balance> {
     Auction,
     BuyNow,
+    /// Descending price auction, the first buyer to accept the current price wins
+    Dutch(DutchAuctionConfig<Balance>),
+    /// Bids are committed as hashes and revealed before the auction ends
+    SealedBid(SealedBidSettlement),
+}
+
+/// Price the winner of a sealed bid auction pays
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum SealedBidSettlement {
+    /// Winner pays their own bid
+    FirstPrice,
+    /// Winner pays the second highest bid (Vickrey auction)
+    SecondPrice,
+}
+
+/// How the price of a dutch auction falls from the start price towards the floor
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PriceDecay<Balance> {
+    /// Price falls every block, reaching the floor at the end of the auction
+    Linear,
+    /// Price drops by `amount` every `interval` blocks
+    Stepwise { interval: u32, amount: Balance },
+}
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DutchAuctionConfig<Balance> {
+    /// Lowest price the auction will drop to
+    pub floor_price: Balance,
+    pub decay: PriceDecay<Balance>,
 }
 
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
@@ -44,7 +76,7 @@ pub struct AuctionItem<AccountId, BlockNumber, Balance> {
     /// Auction start time
     pub start_time: BlockNumber,
     pub end_time: BlockNumber,
-    pub auction_type: AuctionType,
+    pub auction_type: AuctionType<Balance>,
 }
 
 /// Auction info.
@@ -83,7 +115,7 @@ pub trait Auction<AccountId, BlockNumber> {
     ) -> Result<AuctionId, DispatchError>;
 
     fn create_auction(
-        auction_type: AuctionType,
+        auction_type: AuctionType<Self::Balance>,
         item_id: ItemId,
         end: Option<BlockNumber>,
         recipient: AccountId,